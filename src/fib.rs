@@ -1,5 +1,7 @@
 //! Popuplation counts
 
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
 use std::ops::Deref;
 
 // Represents a FIFO queue
@@ -54,13 +56,13 @@ impl<T: Copy> Deref for Queue<T> {
 
 #[derive(Debug)]
 pub struct Population {
-    // Recent population counts used in the recurrence relation
+    // Recent population counts used in the recurrence relation. For a population with a life
+    // expectancy, these are instead the number of pairs of each age in months, youngest first
     counts: Queue<usize>,
     // Number of children pairs per parent pair
     litter: usize,
     // Expected life of a pair
     life_expectancy: Option<usize>,
-    index: usize,
 }
 
 // Implement `Iterator` for `Population`.
@@ -69,46 +71,159 @@ impl Iterator for Population {
     type Item = usize;
 
     fn next(&mut self) -> Option<usize> {
-        let new_next: usize;
-
-        if let Some(life_expectancy) = self.life_expectancy {
-            if self.index < life_expectancy - 1 {
-                new_next = (*self.counts)[0] + (*self.counts)[1] * self.litter;
-            } else if self.index < life_expectancy {
-                new_next = (*self.counts)[0] + (*self.counts)[1] * self.litter - 1;
-            } else {
-                new_next = (*self.counts)[0] + (*self.counts)[1] * self.litter
-                    - (*self.counts).last().unwrap();
-            }
+        if self.life_expectancy.is_some() {
+            // Every pair at least a month old reproduces, and pushing the newborn pairs ages
+            // every other pair by a month, removing the pairs that have reached their life
+            // expectancy
+            let alive = self.counts.iter().sum();
+            let births = self.counts.iter().skip(1).sum::<usize>() * self.litter;
+            self.counts.push(births);
+            Some(alive)
         } else {
-            new_next = (*self.counts)[0] + (*self.counts)[1] * self.litter;
+            let new_next = (*self.counts)[0] + (*self.counts)[1] * self.litter;
+            self.counts.push(new_next);
+            Some(self.counts[1])
         }
-        self.counts.push(new_next);
-        self.index += 1;
-        Some(self.counts[1])
     }
 }
 
 /// Creates a population iterator
 pub fn population(litter: usize) -> Population {
+    population_with_start(litter, 0, 1)
+}
+
+/// Creates a population iterator for a population founded by the given numbers of mature and
+/// immature pairs
+///
+/// The first count is the size of the founding population. Only mature pairs reproduce, and
+/// immature pairs mature after one month.
+///
+/// # Example
+/// ```rust
+/// use rosalind::fib::*;
+///
+/// let counts = population_with_start(1, 2, 0).take(4).collect::<Vec<_>>();  // [2, 4, 6, 10]
+/// assert_eq!(counts, vec![2, 4, 6, 10]);
+/// ```
+pub fn population_with_start(
+    litter: usize,
+    initial_mature: usize,
+    initial_immature: usize,
+) -> Population {
+    // The count before the current one is the number of pairs old enough to reproduce
     Population {
-        counts: Queue::from_vec(&[1usize, 0], 0usize),
+        counts: Queue::from_vec(&[initial_mature + initial_immature, initial_mature], 0usize),
         life_expectancy: None,
         litter,
-        index: 0usize,
     }
 }
 
 /// Creates a population iterator for a population whose members have a
 /// specified life expectancy
-pub fn population_with_moratilty(litter: usize, life_expectancy: usize) -> Population {
-    let mut counts = Queue::new(life_expectancy + 1, 0usize);
+///
+/// The population starts with a single newborn pair. Each pair reproduces every month from its
+/// second month onwards, and dies once it is `life_expectancy` months old, so a pair that lives
+/// for 3 months is counted in months 1 to 3 and is gone by month 4. A life expectancy of 0 gives
+/// an empty population.
+///
+/// # Example
+/// ```rust
+/// use rosalind::fib::*;
+///
+/// let count = population_with_mortality(1, 3).nth(5).unwrap();  // 4
+/// assert_eq!(count, 4);
+/// ```
+pub fn population_with_mortality(litter: usize, life_expectancy: usize) -> Population {
+    let mut counts = Queue::new(life_expectancy, 0usize);
     counts.push(1usize);
     Population {
         counts,
         life_expectancy: Some(life_expectancy),
         litter,
-        index: 0usize,
+    }
+}
+
+/// Creates a population iterator for a population whose members have a
+/// specified life expectancy
+#[deprecated(note = "renamed to `population_with_mortality`")]
+pub fn population_with_moratilty(litter: usize, life_expectancy: usize) -> Population {
+    population_with_mortality(litter, life_expectancy)
+}
+
+/// Represents a population whose counts are not bounded in size
+///
+/// This follows the same recurrence as `Population`, but the counts are arbitrary-precision
+/// integers that do not overflow.
+#[cfg(feature = "bigint")]
+#[derive(Debug)]
+pub struct BigPopulation {
+    // Recent population counts used in the recurrence relation, the most recent count first. For
+    // a population with a life expectancy, these are instead the number of pairs of each age in
+    // months, youngest first
+    counts: Vec<BigUint>,
+    // Number of children pairs per parent pair
+    litter: usize,
+    // Expected life of a pair
+    life_expectancy: Option<usize>,
+}
+
+#[cfg(feature = "bigint")]
+impl Iterator for BigPopulation {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let (new_next, current) = if self.life_expectancy.is_some() {
+            let alive = self.counts.iter().sum::<BigUint>();
+            let births = self.counts.iter().skip(1).sum::<BigUint>() * self.litter;
+            (births, alive)
+        } else {
+            let new_next = &self.counts[0] + &self.counts[1] * self.litter;
+            (new_next, self.counts[0].clone())
+        };
+
+        self.counts.insert(0usize, new_next);
+        self.counts.pop();
+        Some(current)
+    }
+}
+
+/// Creates a population iterator with arbitrary-precision counts
+///
+/// Requires the `bigint` feature.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "bigint")]
+/// # {
+/// use rosalind::fib::*;
+///
+/// let count = population_big(3).nth(4).unwrap();  // 19
+/// assert_eq!(count.to_string(), "19");
+/// # }
+/// ```
+#[cfg(feature = "bigint")]
+pub fn population_big(litter: usize) -> BigPopulation {
+    BigPopulation {
+        counts: vec![BigUint::from(1u32), BigUint::from(0u32)],
+        life_expectancy: None,
+        litter,
+    }
+}
+
+/// Creates a population iterator with arbitrary-precision counts, for a population whose members
+/// have a specified life expectancy
+///
+/// Requires the `bigint` feature.
+#[cfg(feature = "bigint")]
+pub fn population_big_with_mortality(litter: usize, life_expectancy: usize) -> BigPopulation {
+    let mut counts = vec![BigUint::from(0u32); life_expectancy];
+    if let Some(newborn) = counts.first_mut() {
+        *newborn = BigUint::from(1u32);
+    }
+    BigPopulation {
+        counts,
+        life_expectancy: Some(life_expectancy),
+        litter,
     }
 }
 
@@ -128,4 +243,78 @@ mod tests {
         q.push(7isize);
         assert_eq!(*q, vec![7isize, 0, 0]);
     }
+
+    #[test]
+    fn it_grows_a_population_from_a_founding_population() {
+        // Two mature pairs with three pairs per litter: 2, 2 + 2 * 3, 8 + 2 * 3, 14 + 8 * 3
+        let counts = population_with_start(3, 2, 0).take(4).collect::<Vec<_>>();
+        assert_eq!(counts, vec![2, 8, 14, 38]);
+
+        // A single immature pair is the default population
+        let default = population(3).take(10).collect::<Vec<_>>();
+        let seeded = population_with_start(3, 0, 1).take(10).collect::<Vec<_>>();
+        assert_eq!(default, seeded);
+    }
+
+    #[test]
+    fn it_counts_mortal_populations() {
+        // Rosalind FIBD sample
+        assert_eq!(population_with_mortality(1, 3).nth(5), Some(4));
+
+        let counts = |life_expectancy| {
+            population_with_mortality(1, life_expectancy)
+                .take(10)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(counts(3), vec![1, 1, 2, 2, 3, 4, 5, 7, 9, 12]);
+        assert_eq!(counts(4), vec![1, 1, 2, 3, 4, 6, 9, 13, 19, 28]);
+        assert_eq!(counts(5), vec![1, 1, 2, 3, 5, 7, 11, 17, 26, 40]);
+
+        // Pairs that die before or as soon as they can reproduce
+        assert_eq!(counts(2), vec![1; 10]);
+        assert_eq!(counts(1), vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(counts(0), vec![0; 10]);
+    }
+
+    #[test]
+    fn it_kills_every_pair_of_a_cohort() {
+        // The first death is the founding pair in month 4, after it has bred twice. The pairs it
+        // bred in month 3 die in month 6 after breeding in months 4 and 5
+        let counts = population_with_mortality(2, 3).take(6).collect::<Vec<_>>();
+        assert_eq!(counts, vec![1, 1, 3, 4, 8, 14]);
+
+        // A life expectancy longer than the number of months behaves like an immortal population
+        let mortal = population_with_mortality(3, 20)
+            .take(20)
+            .collect::<Vec<_>>();
+        let immortal = population(3).take(20).collect::<Vec<_>>();
+        assert_eq!(mortal, immortal);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn it_counts_populations_beyond_usize() {
+        // The 100th Fibonacci number overflows a 64 bit integer
+        let count = population_big(1).nth(99).unwrap();
+        assert!(count > BigUint::from(u64::MAX));
+        assert_eq!(count.to_string(), "354224848179261915075");
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn it_matches_small_populations() {
+        let small = population(2).take(30).collect::<Vec<_>>();
+        let big = population_big(2).take(30).collect::<Vec<_>>();
+        for (s, b) in small.iter().zip(big.iter()) {
+            assert_eq!(BigUint::from(*s), *b);
+        }
+
+        let small = population_with_mortality(2, 3).take(30).collect::<Vec<_>>();
+        let big = population_big_with_mortality(2, 3)
+            .take(30)
+            .collect::<Vec<_>>();
+        for (s, b) in small.iter().zip(big.iter()) {
+            assert_eq!(BigUint::from(*s), *b);
+        }
+    }
 }
@@ -10,8 +10,26 @@
 //! These strings can be labelled. The labelling format used in this project is the FASTA format,
 //! which uses whitespace to separate labels from strands.
 
+use crate::modulo_ops::ModuloOps;
+use crate::perm::checked_factorial_u128;
+#[cfg(feature = "bigint")]
+use crate::perm::factorial_big;
+use crate::{hamming_distance, lexicographic_kmers};
 use modular::{modulo, Modular, Modulo};
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
+#[cfg(feature = "rand")]
+use rand::{seq::SliceRandom, Rng};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt;
+use std::io::{self, BufRead};
+use std::iter;
+use std::ops::{Deref, Index};
 
 // ///// //
 // Types //
@@ -20,9 +38,77 @@ use std::fmt;
 /// List of symbols present in a DNA strand
 pub const DNA_SYMBOLS: [char; 4] = ['A', 'C', 'G', 'T'];
 
+/// List of IUPAC nucleotide codes that may appear in a DNA strand, including ambiguity codes
+pub const IUPAC_DNA_SYMBOLS: [char; 15] = [
+    'A', 'C', 'G', 'T', 'R', 'Y', 'S', 'W', 'K', 'M', 'B', 'D', 'H', 'V', 'N',
+];
+
 /// List of symbols present in an RNA strand
 pub const RNA_SYMBOLS: [char; 4] = ['A', 'C', 'G', 'U'];
 
+/// List of the standard amino acids that form protein strings
+pub const AMINO_ACID_SYMBOLS: [char; 20] = [
+    'A', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W',
+    'Y',
+];
+
+/// Symbol used to mark a stop codon in a protein string
+pub const STOP_SYMBOL: char = '*';
+
+/// Number of symbols per line when displaying FASTA labelled strings
+pub const FASTA_LINE_WIDTH: usize = 60;
+
+/// Errors produced when creating or manipulating genetic strings
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenError {
+    /// A character that is not one of the `DNA_SYMBOLS` was found in a DNA strand
+    InvalidDnaSymbol {
+        /// The offending character
+        symbol: char,
+        /// Position of the character in the (trimmed) strand
+        index: usize,
+    },
+    /// A character that is not one of the `RNA_SYMBOLS` was found in an RNA strand
+    InvalidRnaSymbol {
+        /// The offending character
+        symbol: char,
+        /// Position of the character in the (trimmed) strand
+        index: usize,
+    },
+    /// A character that is not one of the standard amino acids was found in a protein string
+    InvalidAminoAcid(char),
+    /// Two modular values with different moduli were combined
+    MismatchedModulus {
+        /// Modulus of the left-hand operand
+        left: u32,
+        /// Modulus of the right-hand operand
+        right: u32,
+    },
+    /// The strand contains no symbols
+    EmptyStrand,
+    /// A FASTA record has no label
+    EmptyLabel,
+    /// Reading genetic strings from a source failed
+    Io(String),
+    /// Two strands that must be of equal length are not
+    MismatchedLength {
+        /// Length of the first strand
+        left: usize,
+        /// Length of the mismatched strand
+        right: usize,
+    },
+    /// A motif pattern could not be parsed
+    InvalidMotif(String),
+    /// A sequence of symbols that is not a valid RNA codon
+    InvalidCodon(String),
+    /// A string that does not describe the genotype of an organism
+    InvalidGenotype(String),
+    /// A strand has different numbers of two complementary symbols, so they cannot all be paired
+    UnpairedSymbols(char, char),
+    /// A result is too large to be represented
+    Overflow,
+}
+
 /// Defines behaviours for genetic strings
 pub trait GeneticString {
     /// Return the content of a genetic string.
@@ -54,13 +140,16 @@ pub trait GeneticString {
         self.content().chars().count()
     }
 
-    /// Compute the GC content of a genetic string
+    /// Compute the GC content of a genetic string, as a percentage
     ///
     /// The GC content is the proportion of `G` and `C` characters in a strand. As such, it is only
     /// useful for RNA and DNA strands, with no meaning for protein strings. A strand with a high
     /// GC content is considered more stable, due to the `GC` bond being stronger than the other
     /// bonds.
     ///
+    /// This is `gc_ratio` scaled to the range 0 to 100. An empty strand has a GC content of `0.0`.
+    /// Use `try_gc_content` to treat an empty strand as an error instead.
+    ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
@@ -69,23 +158,255 @@ pub trait GeneticString {
     /// # assert_eq!(dna.gc_content(), 60f64);
     /// ```
     fn gc_content(&self) -> f64 {
-        let gc =
-            (count_character('G', &self.content()) + count_character('C', &self.content())) as i32;
-        let dna_len = self.length() as i32;
-        (f64::from(gc) / f64::from(dna_len)) * 100f64
+        self.gc_ratio() * 100f64
+    }
+
+    /// Compute the GC content of a genetic string, as a fraction
+    ///
+    /// This is the proportion of `G` and `C` characters in the strand, in the range 0 to 1. An
+    /// empty strand has a GC ratio of `0.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGTCGCGTA");
+    /// dna.gc_ratio();  // 0.6
+    /// # assert_eq!(dna.gc_ratio(), 0.6);
+    /// ```
+    fn gc_ratio(&self) -> f64 {
+        let dna_len = self.length();
+
+        if dna_len == 0 {
+            return 0f64;
+        }
+
+        let gc = count_character('G', self.content()) + count_character('C', self.content());
+        gc as f64 / dna_len as f64
+    }
+
+    /// Compute the GC content of a genetic string, rejecting empty strands
+    ///
+    /// Returns `GenError::EmptyStrand` if the strand has no symbols, since its GC content is
+    /// undefined.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGTCGCGTA");
+    /// dna.try_gc_content();  // Ok(60.0)
+    /// # assert_eq!(dna.try_gc_content(), Ok(60f64));
+    /// let empty = DNA::new("");
+    /// empty.try_gc_content();  // Err(EmptyStrand)
+    /// # assert_eq!(empty.try_gc_content(), Err(GenError::EmptyStrand));
+    /// ```
+    fn try_gc_content(&self) -> Result<f64, GenError> {
+        if self.length() == 0 {
+            return Err(GenError::EmptyStrand);
+        }
+
+        Ok(self.gc_content())
+    }
+
+    /// Count the number of times each symbol appears in a genetic string
+    ///
+    /// Every distinct character present in the content is counted, so this works for DNA, RNA
+    /// and protein strings alike. The counts are keyed on the symbol, in alphabetical order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGGTAAC");
+    /// dna.symbol_frequencies();  // {'A': 3, 'C': 2, 'G': 2, 'T': 1}
+    /// # assert_eq!(
+    /// #     dna.symbol_frequencies().into_iter().collect::<Vec<_>>(),
+    /// #     vec![('A', 3), ('C', 2), ('G', 2), ('T', 1)]
+    /// # );
+    /// ```
+    fn symbol_frequencies(&self) -> BTreeMap<char, usize> {
+        self.content()
+            .chars()
+            .fold(BTreeMap::new(), |mut counts, symbol| {
+                *counts.entry(symbol).or_insert(0) += 1;
+                counts
+            })
+    }
+}
+
+/// Defines the complement of nucleic acid strands
+///
+/// This is implemented for `DNA` and `RNA`, allowing generic code to reverse complement either
+/// strand type. Protein strings have no complement and do not implement it.
+pub trait Complement: GeneticString + Sized {
+    /// Return the complement of a single symbol of a strand
+    ///
+    /// Returns `None` if the symbol is not valid for the strand type.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// DNA::complement_symbol('A');  // Some('T')
+    /// # assert_eq!(DNA::complement_symbol('A'), Some('T'));
+    /// RNA::complement_symbol('A');  // Some('U')
+    /// # assert_eq!(RNA::complement_symbol('A'), Some('U'));
+    /// RNA::complement_symbol('T');  // None
+    /// # assert_eq!(RNA::complement_symbol('T'), None);
+    /// ```
+    fn complement_symbol(symbol: char) -> Option<char>;
+
+    /// Return the error for a symbol at the given position that is not valid for the strand type
+    fn invalid_symbol(symbol: char, index: usize) -> GenError;
+
+    /// Create a strand of this type from its content
+    fn from_content(content: String) -> Self;
+
+    /// Compute and return the reverse complement of a strand, validating each symbol
+    ///
+    /// Returns the `invalid_symbol` error for the first character (in strand order) that has no
+    /// `complement_symbol`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// DNA::new("AACGGT").try_reverse_complement();  // Ok("ACCGTT")
+    /// # assert_eq!(DNA::new("AACGGT").try_reverse_complement(), Ok(DNA::new("ACCGTT")));
+    /// let invalid = DNA::new("AACGGU");
+    /// invalid.try_reverse_complement();  // Err(InvalidDnaSymbol { symbol: 'U', index: 5 })
+    /// # assert_eq!(
+    /// #     invalid.try_reverse_complement(),
+    /// #     Err(GenError::InvalidDnaSymbol { symbol: 'U', index: 5 })
+    /// # );
+    /// ```
+    fn try_reverse_complement(&self) -> Result<Self, GenError> {
+        let mut complement = self
+            .content()
+            .chars()
+            .enumerate()
+            .map(|(index, symbol)| {
+                Self::complement_symbol(symbol).ok_or_else(|| Self::invalid_symbol(symbol, index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        complement.reverse();
+
+        Ok(Self::from_content(
+            complement.into_iter().collect::<String>(),
+        ))
+    }
+
+    /// Compute and return the reverse complement of a strand
+    ///
+    /// The strand is read backwards and each symbol is replaced by its `complement_symbol`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol that is not valid for the strand type, reporting
+    /// the symbol and its position. Use `try_reverse_complement` to handle this case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// fn reverse_complement<T: Complement>(strand: &T) -> T {
+    ///     strand.reverse_complement()
+    /// }
+    ///
+    /// reverse_complement(&DNA::new("AACGGT"));  // "ACCGTT"
+    /// # assert_eq!(reverse_complement(&DNA::new("AACGGT")), DNA::new("ACCGTT"));
+    /// reverse_complement(&RNA::new("AACGGU"));  // "ACCGUU"
+    /// # assert_eq!(reverse_complement(&RNA::new("AACGGU")), RNA::new("ACCGUU"));
+    /// ```
+    fn reverse_complement(&self) -> Self {
+        self.try_reverse_complement()
+            .unwrap_or_else(|err| panic!("{}", err))
     }
-    // fn symbol_count(&self) -> Vec<usize>;
 }
 
 /// Represents a strand of DNA
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String")
+)]
 pub struct DNA(String);
 
 /// Represents a strand of RNA
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String")
+)]
 pub struct RNA(String);
 
 /// Represents a Protein string formed from RNA strands
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "String")
+)]
 pub struct Protein(String);
 
+/// Selects the NCBI genetic code used to translate RNA codons into amino acids
+///
+/// Each table differs from the standard code in only a few codons. The standard code is the
+/// default, and is the one used by `translate_codon` and `Protein::from`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// GeneticCode::Standard.translate_codon("UGA");  // Ok(None)
+/// # assert_eq!(GeneticCode::Standard.translate_codon("UGA"), Ok(None));
+/// GeneticCode::VertebrateMitochondrial.translate_codon("UGA");  // Ok(Some('W'))
+/// # assert_eq!(GeneticCode::VertebrateMitochondrial.translate_codon("UGA"), Ok(Some('W')));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GeneticCode {
+    /// The standard code (NCBI table 1)
+    #[default]
+    Standard,
+    /// The vertebrate mitochondrial code (NCBI table 2)
+    VertebrateMitochondrial,
+    /// The yeast mitochondrial code (NCBI table 3)
+    YeastMitochondrial,
+    /// The invertebrate mitochondrial code (NCBI table 5)
+    InvertebrateMitochondrial,
+}
+
+/// Represents a motif pattern over protein or nucleotide symbols
+///
+/// A motif is written as a sequence of symbols, where each position is one of:
+///
+/// - a single symbol, such as `N`, which must match exactly
+/// - a bracketed group, such as `[ST]`, which matches any of the symbols in it
+/// - a braced group, such as `{P}`, which matches any symbol except those in it
+///
+/// For example, the N-glycosylation motif is written as `N{P}[ST]{P}`, and `A[CT]G` matches the
+/// DNA strings `ACG` and `ATG`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let motif = Motif::try_new("N{P}[ST]{P}");
+/// # assert!(motif.is_ok());
+/// let invalid = Motif::try_new("N{P");  // Err(InvalidMotif("N{P"))
+/// # assert_eq!(invalid.err(), Some(GenError::InvalidMotif("N{P".to_string())));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Motif(Vec<MotifPosition>);
+
+/// Represents a protein motif pattern
+///
+/// Protein motifs are written in the same way as any other `Motif`.
+pub type ProteinMotif = Motif;
+
+// A single position in a motif
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum MotifPosition {
+    Exact(char),
+    AnyOf(Vec<char>),
+    NoneOf(Vec<char>),
+}
+
 /// Represents a FASTA format labelled string
 ///
 /// ```text
@@ -115,6 +436,49 @@ pub struct FASTA {
     label: String,
 }
 
+/// Reads FASTA labelled DNA strings from a buffered source, one record at a time
+///
+/// Records are parsed in the same way as `FASTA::parse_many`, but only the record being read is
+/// held in memory.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// use std::io::Cursor;
+///
+/// let mut reader = FastaReader::new(Cursor::new(">DNA_1\nACGT\n>DNA_2\nTTGCA"));
+/// let fasta = reader.next().unwrap().unwrap();
+/// fasta.label();  // "DNA_1"
+/// # assert_eq!(fasta.label(), "DNA_1");
+/// let fasta = reader.next().unwrap().unwrap();
+/// fasta.content();  // "TTGCA"
+/// # assert_eq!(fasta.content(), "TTGCA");
+/// # assert!(reader.next().is_none());
+/// ```
+pub struct FastaReader<R: BufRead> {
+    lines: io::Lines<R>,
+    // Header of the record currently being read
+    header: Option<String>,
+}
+
+/// Summarizes the lengths of the strands in a collection of FASTA records
+///
+/// This is created by `assembly_stats`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssemblyStats {
+    /// Sum of the lengths of the strands
+    pub total_length: usize,
+    /// Number of records
+    pub count: usize,
+    /// Mean length of the strands
+    pub mean_length: f64,
+    /// Median length of the strands
+    pub median_length: f64,
+    /// Largest length `L` such that the strands of length `L` or longer cover at least half of the
+    /// total length
+    pub n50: usize,
+}
+
 // /////////////// //
 // Implementations //
 // /////////////// //
@@ -125,6 +489,9 @@ pub struct FASTA {
 impl DNA {
     /// Initialize and return a new DNA struct
     ///
+    /// The symbols in the string are not validated. Use `DNA::try_new` when the input may contain
+    /// characters other than the `DNA_SYMBOLS`.
+    ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::DNA;
@@ -134,345 +501,4216 @@ impl DNA {
         DNA(String::from(dna_string.trim()))
     }
 
-    /// Compute and return the reverse complement of a DNA strand
+    /// Initialize and return a new DNA struct, validating each symbol
     ///
-    /// # Example
+    /// Returns an error carrying the first character that is not one of the `DNA_SYMBOLS`, along
+    /// with its position in the trimmed string. An empty (or whitespace-only) string is rejected
+    /// with `GenError::EmptyStrand`.
     ///
-    /// ```
+    /// # Example
+    /// ```rust
     /// # use rosalind::gen_str::*;
-    /// let dna = DNA::new("AACGGT");
-    /// dna.reverse_complement().content(); // "ACCGTT"
-    /// # assert_eq!(dna.reverse_complement().content(), DNA::new("ACCGTT").content());
+    /// let dna = DNA::try_new("ACGT");
+    /// # assert!(dna.is_ok());
+    /// let invalid = DNA::try_new("ACGTX");  // Err(InvalidDnaSymbol { symbol: 'X', index: 4 })
+    /// # assert_eq!(invalid.err(), Some(GenError::InvalidDnaSymbol { symbol: 'X', index: 4 }));
     /// ```
-    pub fn reverse_complement(&self) -> DNA {
-        let DNA(ref dna_string) = *self;
-        DNA(reverse_string(&dna_string)
+    pub fn try_new(dna_string: &str) -> Result<DNA, GenError> {
+        let dna_string = dna_string.trim();
+
+        if dna_string.is_empty() {
+            return Err(GenError::EmptyStrand);
+        }
+
+        match dna_string
             .chars()
-            .map(DNA::complement)
-            .collect::<String>())
+            .enumerate()
+            .find(|(_, symbol)| !DNA_SYMBOLS.contains(symbol))
+        {
+            Some((index, symbol)) => Err(GenError::InvalidDnaSymbol { symbol, index }),
+            None => Ok(DNA::new(dna_string)),
+        }
     }
 
-    /// Count the number of times each DNA symbol appears in a DNA string
+    /// Generate a DNA strand of the given length, with each symbol chosen uniformly at random
+    /// from the `DNA_SYMBOLS`
     ///
-    /// The resulting vector is in the order `[A, C, G, T]`
+    /// Requires the `rand` feature.
     ///
     /// # Example
-    ///
-    /// ```
+    /// ```rust
+    /// # #[cfg(feature = "rand")]
+    /// # {
     /// # use rosalind::gen_str::*;
-    /// let dna = DNA::new("ACGGTAAC");
-    /// dna.count_symbols(); // [3, 2, 2, 1] (Vector)
-    /// # assert_eq!(dna.count_symbols(), vec![3, 2, 2, 1]);
+    /// let dna = DNA::random(10, &mut rand::thread_rng());
+    /// dna.length();  // 10
+    /// # assert_eq!(dna.length(), 10);
+    /// # }
     /// ```
-    pub fn count_symbols(&self) -> Vec<usize> {
-        DNA_SYMBOLS
-            .iter()
-            .map(|symbol| count_character(*symbol, self.content()))
-            .collect::<Vec<_>>()
-    }
-
-    // Return the complement for each DNA character
-    fn complement(symbol: char) -> char {
-        DNA_SYMBOLS[DNA_SYMBOLS
-            .iter()
-            .rev()
-            .position(|&x| x == symbol)
-            .expect("Invalid DNA string")]
-    }
-}
-
-impl GeneticString for DNA {
-    fn content(&self) -> &str {
-        let DNA(ref content) = *self;
-        content
+    #[cfg(feature = "rand")]
+    pub fn random(len: usize, rng: &mut impl Rng) -> DNA {
+        DNA(random_string(&DNA_SYMBOLS, len, rng))
     }
-}
 
-impl From<RNA> for DNA {
-    /// Convert an RNA strand into a DNA strand
+    /// Find every character of this DNA strand that is not one of the `DNA_SYMBOLS`
+    ///
+    /// Returns the 0-based position and the character of each one, in order. An empty vector
+    /// means that the strand is valid.
     ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
-    /// let rna = RNA::new("ACGUUGCA");
-    /// let dna = DNA::from(rna);  // "ACGTTGCA"
-    /// # assert_eq!(dna.content(), "ACGTTGCA");
+    /// let dna = DNA::new("ACNGTXA");
+    /// dna.invalid_positions();  // [(2, 'N'), (5, 'X')]
+    /// # assert_eq!(dna.invalid_positions(), vec![(2, 'N'), (5, 'X')]);
     /// ```
-    fn from(rna: RNA) -> Self {
-        let RNA(ref rna_string) = rna;
-
-        let dna_string = rna_string.chars().map(get_dna_symbol).collect::<String>();
-
-        DNA::new(&dna_string)
+    pub fn invalid_positions(&self) -> Vec<(usize, char)> {
+        invalid_positions(self.content(), |symbol| DNA_SYMBOLS.contains(&symbol))
     }
-}
-
-impl fmt::Display for DNA {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.content())
-    }
-}
-
-// RNA
-// --
 
-impl RNA {
-    /// Initialize and return a new RNA struct
+    /// Return a copy of this DNA strand with every symbol in uppercase
+    ///
+    /// The complement and transcription methods expect uppercase symbols. When reading strands
+    /// from a messy source, convert them to uppercase first and then validate them with
+    /// `DNA::try_new`.
     ///
     /// # Example
     /// ```rust
-    /// # use rosalind::gen_str::RNA;
-    /// let rna = RNA::new("ACGU");
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("acgtn").to_uppercase();  // "ACGTN"
+    /// # assert_eq!(dna.content(), "ACGTN");
+    /// DNA::try_new(dna.content());  // Err(InvalidDnaSymbol { symbol: 'N', index: 4 })
+    /// # assert_eq!(
+    /// #     DNA::try_new(dna.content()).err(),
+    /// #     Some(GenError::InvalidDnaSymbol { symbol: 'N', index: 4 })
+    /// # );
     /// ```
-    pub fn new(rna_string: &str) -> RNA {
-        RNA(String::from(rna_string.trim()))
-    }
-}
-
-impl GeneticString for RNA {
-    fn content(&self) -> &str {
-        let RNA(ref content) = *self;
-        content
+    pub fn to_uppercase(&self) -> DNA {
+        DNA(self.content().to_uppercase())
     }
-}
 
-impl From<DNA> for RNA {
-    /// Convert a DNA strand into an RNA strand
+    /// Transcribe this DNA strand into an RNA strand, without consuming it
+    ///
+    /// This is the same conversion as `RNA::from`, so the case of each symbol is preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`, in either case.
     ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
     /// let dna = DNA::new("CGTACGATCG");
-    /// let rna = RNA::from(dna);  // "CGUACGAUCG"
-    /// # assert_eq!(rna.content(), "CGUACGAUCG");
+    /// dna.transcribe();  // "CGUACGAUCG"
+    /// # assert_eq!(dna.transcribe().content(), "CGUACGAUCG");
     /// ```
-    fn from(dna: DNA) -> Self {
-        let DNA(ref dna_string) = dna;
+    pub fn transcribe(&self) -> RNA {
+        let DNA(ref dna_string) = *self;
 
-        let rna_string = dna_string.chars().map(get_rna_symbol).collect::<String>();
+        let rna_string = dna_string
+            .chars()
+            .enumerate()
+            .map(|(index, symbol)| get_rna_symbol(symbol, index))
+            .collect::<Result<String, _>>()
+            .unwrap_or_else(|err| panic!("{}", err));
 
         RNA::new(&rna_string)
     }
-}
 
-impl fmt::Display for RNA {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.content())
+    /// Compute and return the reverse complement of a DNA strand
+    ///
+    /// The strand is read backwards and complemented, as for `Complement::reverse_complement`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`, reporting the symbol
+    /// and its position. Use `Complement::try_reverse_complement` to handle this case, or
+    /// `DNA::reverse_complement_iupac` for strands with ambiguity codes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("AACGGT");
+    /// dna.reverse_complement(); // "ACCGTT"
+    /// # assert_eq!(dna.reverse_complement(), DNA::new("ACCGTT"));
+    /// ```
+    pub fn reverse_complement(&self) -> DNA {
+        Complement::reverse_complement(self)
     }
-}
-
-// Protein
-// --
 
-impl Protein {
-    /// Initialize and return a new Protein string
+    /// Compute and return the complement of a DNA strand, without reversing it
+    ///
+    /// Each base is replaced by its pair in place, giving the opposite strand read from its 3' end
+    /// to its 5' end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`.
     ///
     /// # Example
     /// ```rust
-    /// # use rosalind::gen_str::Protein;
-    /// let protein = Protein::new("MTSMSS");
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("AACGGT");
+    /// dna.complement_strand();  // "TTGCCA"
+    /// # assert_eq!(dna.complement_strand(), DNA::new("TTGCCA"));
     /// ```
-    pub fn new(protein_string: &str) -> Protein {
-        Protein(String::from(protein_string.trim()))
-    }
-
-    /// Determine the number of possible RNA strands that would form this protein string
-    pub fn rna_count(&self, modulus: u32) -> Modulo {
-        self.content()
-            .chars()
-            .fold(modulo!(rna_codon(' ').len() as i32, modulus), |acc, ch| {
-                acc * modulo!(rna_codon(ch).len() as i32, modulus)
-            })
-    }
-}
-
-impl GeneticString for Protein {
-    fn content(&self) -> &str {
-        let Protein(ref content) = *self;
-        content
+    pub fn complement_strand(&self) -> DNA {
+        let DNA(ref dna_string) = *self;
+        DNA(dna_string.chars().map(DNA::complement).collect())
     }
-}
 
-impl From<RNA> for Protein {
-    /// Convert an RNA strand into a Protein string
+    /// Compute and return the reverse complement of a DNA strand that may contain IUPAC ambiguity
+    /// codes
     ///
-    /// This is a one-way conversion due to the requirement that the RNA strand be divided into
-    /// chunks of 3. If the strand is not divisible by 3, the remaining characters are ignored.
-    /// Therefore, converting backwards from a protein string into an RNA strand may lack up to 2
-    /// characters that were present in the original RNA strand
+    /// Each symbol is complemented with `DNA::complement_iupac`. Use `DNA::reverse_complement`
+    /// where only the `DNA_SYMBOLS` should be accepted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol that is not one of the `IUPAC_DNA_SYMBOLS`.
     ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
-    /// let rna = RNA::new("AAGUGUCUGGCUUGAAGU");
-    /// let protein = Protein::from(rna);  // "KCLAS"
-    /// # assert_eq!(protein.content(), "KCLAS");
+    /// let dna = DNA::new("ARNGTK");
+    /// dna.reverse_complement_iupac();  // "MACNYT"
+    /// # assert_eq!(dna.reverse_complement_iupac(), DNA::new("MACNYT"));
     /// ```
-    fn from(rna: RNA) -> Self {
-        let RNA(ref rna_string) = rna;
-
-        let rna_chars: Vec<char> = rna_string.chars().collect();
-        let string_arr = &rna_chars
-            .chunks(3)
-            .map(|chunk| chunk.iter().collect::<String>())
-            .collect::<Vec<_>>();
+    pub fn reverse_complement_iupac(&self) -> DNA {
+        let DNA(ref dna_string) = *self;
+        DNA(dna_string
+            .chars()
+            .rev()
+            .map(DNA::complement_iupac)
+            .collect::<String>())
+    }
 
-        let p_string = string_arr
-            .iter()
-            .map(|cd| codon_table(&cd))
-            .collect::<Vec<_>>();
+    /// Return the complement of a DNA symbol, which may be an IUPAC ambiguity code
+    ///
+    /// An ambiguity code is complemented to the code for the complements of the nucleotides it
+    /// stands for, so `R` (A or G) pairs with `Y` (C or T), and `S`, `W` and `N` are their own
+    /// complements. Lowercase symbols are complemented in lowercase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol is not one of the `IUPAC_DNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// DNA::complement_iupac('R');  // 'Y'
+    /// # assert_eq!(DNA::complement_iupac('R'), 'Y');
+    /// DNA::complement_iupac('n');  // 'n'
+    /// # assert_eq!(DNA::complement_iupac('n'), 'n');
+    /// ```
+    pub fn complement_iupac(symbol: char) -> char {
+        let complement = match symbol.to_ascii_uppercase() {
+            'A' => 'T',
+            'T' => 'A',
+            'C' => 'G',
+            'G' => 'C',
+            'R' => 'Y',
+            'Y' => 'R',
+            'K' => 'M',
+            'M' => 'K',
+            'B' => 'V',
+            'V' => 'B',
+            'D' => 'H',
+            'H' => 'D',
+            code @ 'S' | code @ 'W' | code @ 'N' => code,
+            _ => panic!("Invalid IUPAC DNA symbol '{}'", symbol),
+        };
 
-        Protein::new(&p_string.join(""))
+        with_case_of(complement, symbol)
     }
-}
 
-impl fmt::Display for Protein {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.content())
+    /// Return the symbol at the given (0-based) position of this DNA strand
+    ///
+    /// Positions are counted in characters. Returns `None` if the position is past the end of the
+    /// strand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGT");
+    /// dna.base_at(2);  // Some('G')
+    /// # assert_eq!(dna.base_at(2), Some('G'));
+    /// dna.base_at(4);  // None
+    /// # assert_eq!(dna.base_at(4), None);
+    /// ```
+    pub fn base_at(&self, index: usize) -> Option<char> {
+        self.content().chars().nth(index)
     }
-}
 
-// FASTA
-// --
+    /// Return the subsequence of this DNA strand with the given (0-based) start and length
+    ///
+    /// Positions are counted in characters. Returns `None` if the subsequence would extend past
+    /// the end of the strand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGTACGT");
+    /// dna.subseq(2, 3);  // Some("GTA")
+    /// # assert_eq!(dna.subseq(2, 3), Some(DNA::new("GTA")));
+    /// dna.subseq(6, 3);  // None
+    /// # assert_eq!(dna.subseq(6, 3), None);
+    /// ```
+    pub fn subseq(&self, start: usize, len: usize) -> Option<DNA> {
+        let content = self.content();
+        let mut boundaries = content
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(iter::once(content.len()));
 
-impl FASTA {
-    /// Initialize and return a new FASTA labelled genetic string
+        let from = boundaries.nth(start)?;
+        let to = if len == 0 {
+            from
+        } else {
+            boundaries.nth(len - 1)?
+        };
+
+        Some(DNA::new(&content[from..to]))
+    }
+
+    /// Count the number of times each DNA symbol appears in a DNA string
+    ///
+    /// The resulting vector is in the order `[A, C, G, T]`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGGTAAC");
+    /// dna.count_symbols(); // [3, 2, 2, 1] (Vector)
+    /// # assert_eq!(dna.count_symbols(), vec![3, 2, 2, 1]);
+    /// ```
+    pub fn count_symbols(&self) -> Vec<usize> {
+        self.content()
+            .chars()
+            .fold([0usize; 4], |mut counts, symbol| {
+                match symbol {
+                    'A' => counts[0] += 1,
+                    'C' => counts[1] += 1,
+                    'G' => counts[2] += 1,
+                    'T' => counts[3] += 1,
+                    _ => (),
+                }
+                counts
+            })
+            .to_vec()
+    }
+
+    /// Count the occurrences of every possible k-mer in a DNA string
+    ///
+    /// The counts are returned in the lexicographic order of the k-mers over the `DNA_SYMBOLS`
+    /// (`AA..A`, `AA..C`, `AA..G`, ...), as produced by `dna_kmers`. The resulting vector has
+    /// `4^k` entries. Windows that contain a symbol other than the `DNA_SYMBOLS` are not counted
+    /// and a `k` of 0 returns an empty vector.
     ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
-    /// let fasta = FASTA::new(DNA::new("ACGTTGCATC"), "DNA_1");
+    /// let dna = DNA::new("ACGTA");
+    /// dna.kmer_composition(1);  // [2, 1, 1, 1]
+    /// # assert_eq!(dna.kmer_composition(1), vec![2, 1, 1, 1]);
+    /// let counts = dna.kmer_composition(2);
+    /// counts[1];  // 1 (AC)
+    /// # assert_eq!(counts[1], 1);
+    /// counts[12]; // 1 (TA)
+    /// # assert_eq!(counts[12], 1);
     /// ```
-    pub fn new<T: GeneticString + 'static>(gen_string: T, label: &str) -> FASTA {
-        FASTA {
-            content: Box::new(gen_string),
-            label: String::from(label),
+    pub fn kmer_composition(&self, k: usize) -> Vec<usize> {
+        if k == 0 {
+            return vec![];
+        }
+
+        let mut counts = vec![0usize; DNA_SYMBOLS.len().pow(k as u32)];
+        let symbols = self.content().chars().collect::<Vec<_>>();
+
+        for window in symbols.windows(k) {
+            let index = window.iter().try_fold(0usize, |acc, symbol| {
+                DNA_SYMBOLS
+                    .iter()
+                    .position(|x| x == symbol)
+                    .map(|position| acc * DNA_SYMBOLS.len() + position)
+            });
+
+            if let Some(index) = index {
+                counts[index] += 1;
+            }
         }
+
+        counts
     }
 
-    /// Get the label of this FASTA string
+    /// Compute the GC content of each window of this DNA strand
+    ///
+    /// The window of `window` symbols starts at the beginning of the strand and slides along it by
+    /// `step` symbols at a time. Each GC content is a percentage, as with `gc_content`. A final
+    /// window that would extend past the end of the strand is excluded, so an empty vector is
+    /// returned if the window is longer than the strand. A `window` or `step` of 0 also returns an
+    /// empty vector.
     ///
     /// # Example
     /// ```rust
     /// # use rosalind::gen_str::*;
-    /// let fasta = FASTA::new(DNA::new("ACGTTGCATC"), "DNA_1");
-    /// fasta.label(); // "DNA_1"
-    /// # assert_eq!(fasta.label(), "DNA_1");
+    /// let dna = DNA::new("GCGCATAT");
+    /// dna.gc_content_windows(4, 2);  // [100.0, 50.0, 0.0]
+    /// # assert_eq!(dna.gc_content_windows(4, 2), vec![100f64, 50f64, 0f64]);
     /// ```
-    pub fn label(&self) -> String {
-        self.label.clone()
+    pub fn gc_content_windows(&self, window: usize, step: usize) -> Vec<f64> {
+        if window == 0 || step == 0 {
+            return vec![];
+        }
+
+        let symbols = self.content().chars().collect::<Vec<_>>();
+
+        symbols
+            .windows(window)
+            .step_by(step)
+            .map(|symbols| {
+                let gc = symbols.iter().filter(|&&x| x == 'G' || x == 'C').count();
+                (gc as f64 / window as f64) * 100f64
+            })
+            .collect::<Vec<_>>()
     }
-}
 
-impl GeneticString for FASTA {
-    fn content(&self) -> &str {
-        (*self.content).content()
+    /// Compute the cumulative GC skew of this DNA strand
+    ///
+    /// The skew at each position is the number of `G` minus the number of `C` symbols from the
+    /// start of the strand up to, and including, that position. Its minimum often marks the origin
+    /// of replication.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("CATGGGCATCGGCCATACGCC");
+    /// let skew = dna.gc_skew();
+    /// &skew[..6];  // [-1, -1, -1, 0, 1, 2]
+    /// # assert_eq!(&skew[..6], &[-1, -1, -1, 0, 1, 2]);
+    /// ```
+    pub fn gc_skew(&self) -> Vec<i64> {
+        self.content()
+            .chars()
+            .scan(0i64, |skew, symbol| {
+                match symbol {
+                    'G' => *skew += 1,
+                    'C' => *skew -= 1,
+                    _ => {}
+                }
+                Some(*skew)
+            })
+            .collect::<Vec<_>>()
     }
-}
 
-// ///////// //
-// Functions //
-// ///////// //
+    /// Find the 0-based positions at which the cumulative GC skew of this DNA strand is minimal
+    ///
+    /// See `DNA::gc_skew`. The positions are in increasing order, and an empty strand has none.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("GGCCCCAGGC");
+    /// dna.min_skew_positions();  // [5, 6]
+    /// # assert_eq!(dna.min_skew_positions(), vec![5, 6]);
+    /// ```
+    pub fn min_skew_positions(&self) -> Vec<usize> {
+        let skew = self.gc_skew();
 
-// Count the number of times a character occurs in the given string
-fn count_character(character: char, in_string: &str) -> usize {
-    in_string.chars().filter(|ch| *ch == character).count()
-}
+        match skew.iter().min() {
+            Some(&min) => skew
+                .iter()
+                .enumerate()
+                .filter(|&(_, &value)| value == min)
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>(),
+            None => vec![],
+        }
+    }
 
-// Reverse a given string
-fn reverse_string(input: &str) -> String {
-    input.chars().rev().collect::<String>()
-}
+    /// Find every distinct protein encoded by an open reading frame of this DNA strand
+    ///
+    /// Both the strand and its reverse complement are transcribed into RNA, and every `AUG` start
+    /// codon, in any reading frame, is translated up to the next stop codon. Reading frames with
+    /// no stop codon in frame are discarded. The proteins are listed once each, in the order they
+    /// are first found.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ATGGCCTAACCATTAGGCCAT");
+    /// let proteins = dna.open_reading_frames();  // ["MA"]
+    /// # assert_eq!(proteins.len(), 1);
+    /// # assert_eq!(proteins[0].content(), "MA");
+    /// ```
+    pub fn open_reading_frames(&self) -> Vec<Protein> {
+        let mut proteins: Vec<Protein> = vec![];
+
+        for strand in &[self.clone(), self.reverse_complement()] {
+            let rna = strand.transcribe();
+            let rna_string = rna.content();
+
+            for (start, _) in rna_string.match_indices("AUG") {
+                let codons = split_codons(&rna_string[start..]).collect::<Vec<_>>();
+
+                if let Some(stop) = codons.iter().position(|cd| is_stop_codon(cd)) {
+                    let p_string = codons[..stop]
+                        .iter()
+                        .filter_map(|cd| codon_table(cd))
+                        .collect::<String>();
+
+                    if !proteins.iter().any(|protein| protein.content() == p_string) {
+                        proteins.push(Protein::new(&p_string));
+                    }
+                }
+            }
+        }
+
+        proteins
+    }
 
-// Return the RNA symbol that corresponds to the given DNA symbol
-fn get_rna_symbol(symbol: char) -> char {
-    RNA_SYMBOLS[DNA_SYMBOLS.iter().position(|&x| x == symbol).unwrap()]
+    /// Transcribe the six reading frames of this DNA strand into RNA
+    ///
+    /// The frames are returned in the following order:
+    ///
+    /// - 0 to 2: the strand, starting from its first, second and third symbol
+    /// - 3 to 5: the reverse complement of the strand, starting from its first, second and third
+    ///   symbol
+    ///
+    /// Frame 0 is therefore the transcription of the whole strand. Trailing symbols that do not
+    /// form a whole codon are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let frames = DNA::new("ATGCGT").reading_frames();
+    /// frames[1].content();  // "UGCGU"
+    /// # assert_eq!(frames[1], RNA::new("UGCGU"));
+    /// frames[3].content();  // "ACGCAU"
+    /// # assert_eq!(frames[3], RNA::new("ACGCAU"));
+    /// ```
+    pub fn reading_frames(&self) -> [RNA; 6] {
+        let forward = self.transcribe();
+        let reverse = self.reverse_complement().transcribe();
+        let frame = |rna: &RNA, offset: usize| RNA(rna.content().chars().skip(offset).collect());
+
+        [
+            frame(&forward, 0),
+            frame(&forward, 1),
+            frame(&forward, 2),
+            frame(&reverse, 0),
+            frame(&reverse, 1),
+            frame(&reverse, 2),
+        ]
+    }
+
+    /// Return `true` if this whole DNA strand is equal to its reverse complement
+    ///
+    /// A strand that contains a symbol other than the `DNA_SYMBOLS` is not a reverse palindrome.
+    /// Use `DNA::reverse_palindromes` to find the reverse palindromes within a strand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// DNA::new("GCATGC").is_reverse_palindrome();  // true
+    /// # assert!(DNA::new("GCATGC").is_reverse_palindrome());
+    /// DNA::new("GCATGG").is_reverse_palindrome();  // false
+    /// # assert!(!DNA::new("GCATGG").is_reverse_palindrome());
+    /// ```
+    pub fn is_reverse_palindrome(&self) -> bool {
+        let content = self.content();
+
+        content
+            .chars()
+            .zip(content.chars().rev())
+            .all(|(symbol, mirrored)| {
+                DNA_SYMBOLS.contains(&symbol) && DNA::complement(symbol) == mirrored
+            })
+    }
+
+    /// Find every reverse palindrome in this DNA strand
+    ///
+    /// A reverse palindrome is a substring that is equal to its own reverse complement. Returns
+    /// the 0-based starting position and the length of each reverse palindrome whose length is
+    /// between `min_len` and `max_len` (inclusive), ordered by position and then by length. Since
+    /// no DNA symbol is its own complement, reverse palindromes always have an even length.
+    /// Substrings that contain a symbol other than the `DNA_SYMBOLS` are not reverse palindromes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("TCAATGCATGCGGGTCTATATGCAT");
+    /// let palindromes = dna.reverse_palindromes(4, 12);
+    /// &palindromes[..3];  // [(3, 6), (4, 4), (5, 6)]
+    /// # assert_eq!(&palindromes[..3], &[(3, 6), (4, 4), (5, 6)]);
+    /// ```
+    pub fn reverse_palindromes(&self, min_len: usize, max_len: usize) -> Vec<(usize, usize)> {
+        let symbols = self.content().chars().collect::<Vec<_>>();
+        let mut palindromes: Vec<(usize, usize)> = vec![];
+
+        for start in 0..symbols.len() {
+            for length in min_len.max(1)..=max_len.min(symbols.len() - start) {
+                let substring = DNA(symbols[start..start + length].iter().collect::<String>());
+
+                if substring.is_reverse_palindrome() {
+                    palindromes.push((start, length));
+                }
+            }
+        }
+
+        palindromes
+    }
+
+    /// Remove introns from this DNA strand, returning the spliced strand
+    ///
+    /// The introns are removed in the order they are given, each from the strand left by the
+    /// previous removals. Only the first (leftmost) occurrence of each intron is removed, and an
+    /// intron that does not occur in the strand is ignored. Since removals happen in order, an
+    /// intron that overlaps an earlier one may no longer be found once the earlier one is removed.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new(
+    ///     "ATGGTCTACATAGCTGACAAACAGCACGTAGCAATCGGTCGAATCTCGAGAGGCATATGGTCACATGATCGGTCGAGCGTGTTTCAAAGTTTGCGCCTAG",
+    /// );
+    /// let exons = dna.splice(&["ATCGGTCGAA", "ATCGGTCGAGCGTGT"]);
+    /// let protein = Protein::from(RNA::from(exons));  // "MVYIADKQHVASREAYGHMFKVCA"
+    /// # assert_eq!(protein.content(), "MVYIADKQHVASREAYGHMFKVCA");
+    /// ```
+    pub fn splice(&self, introns: &[&str]) -> DNA {
+        let dna_string = introns
+            .iter()
+            .fold(self.content().to_string(), |acc, intron| {
+                acc.replacen(intron, "", 1)
+            });
+
+        DNA::new(&dna_string)
+    }
+
+    // Return the complement for each DNA character
+    fn complement(symbol: char) -> char {
+        DNA::complement_base(symbol).expect("Invalid DNA string")
+    }
+
+    // Return the complement of a DNA character, or `None` if it is not one of the `DNA_SYMBOLS`
+    fn complement_base(symbol: char) -> Option<char> {
+        DNA_SYMBOLS
+            .iter()
+            .rev()
+            .position(|&x| x == symbol)
+            .map(|position| DNA_SYMBOLS[position])
+    }
 }
 
-// Return the DNA symbol that corresponds to the given RNA symbol
-fn get_dna_symbol(symbol: char) -> char {
-    DNA_SYMBOLS[RNA_SYMBOLS.iter().position(|&x| x == symbol).unwrap()]
+impl GeneticString for DNA {
+    fn content(&self) -> &str {
+        let DNA(ref content) = *self;
+        content
+    }
 }
 
-// Return the protein string produced by the given RNA strand
-fn codon_table(rna_slice: &str) -> &str {
-    match rna_slice {
-        "GGU" | "GGC" | "GGA" | "GGG" => "G",
-        "GUU" | "GUC" | "GUA" | "GUG" => "V",
-        "GCU" | "GCC" | "GCA" | "GCG" => "A",
-        "ACG" | "ACA" | "ACC" | "ACU" => "T",
-        "CGG" | "CGA" | "CGC" | "CGU" | "AGG" | "AGA" => "R",
-        "CUG" | "CUA" | "CUC" | "CUU" | "UUG" | "UUA" => "L",
-        "CCG" | "CCA" | "CCC" | "CCU" => "P",
-        "UCG" | "UCA" | "UCC" | "UCU" | "AGC" | "AGU" => "S",
-        "AUA" | "AUC" | "AUU" => "I",
-        "UAG" | "UGA" | "UAA" => "",
-        "GAU" | "GAC" => "D",
-        "GAA" | "GAG" => "E",
-        "AAU" | "AAC" => "N",
-        "AAA" | "AAG" => "K",
-        "CAC" | "CAU" => "H",
-        "CAG" | "CAA" => "Q",
-        "UUC" | "UUU" => "F",
-        "UAC" | "UAU" => "Y",
-        "UGC" | "UGU" => "C",
-        "AUG" => "M",
-        "UGG" => "W",
-        _ => "",
+impl Complement for DNA {
+    fn complement_symbol(symbol: char) -> Option<char> {
+        DNA::complement_base(symbol)
+    }
+
+    fn invalid_symbol(symbol: char, index: usize) -> GenError {
+        GenError::InvalidDnaSymbol { symbol, index }
+    }
+
+    fn from_content(content: String) -> DNA {
+        DNA(content)
     }
 }
 
-fn rna_codon(amino_acid: char) -> Vec<&'static str> {
-    match amino_acid {
-        'A' => vec!["GCU", "GCC", "GCA", "GCG"],
-        'C' => vec!["UGC", "UGU"],
-        'D' => vec!["GAU", "GAC"],
-        'E' => vec!["GAA", "GAG"],
-        'F' => vec!["UUC", "UUU"],
-        'G' => vec!["GGU", "GGC", "GGA", "GGG"],
-        'H' => vec!["CAC", "CAU"],
-        'I' => vec!["AUA", "AUC", "AUU"],
-        'K' => vec!["AAA", "AAG"],
-        'L' => vec!["UUG", "UUA", "CUG", "CUA", "CUC", "CUU"],
-        'M' => vec!["AUG"],
-        'N' => vec!["AAU", "AAC"],
-        'P' => vec!["CCG", "CCA", "CCC", "CCU"],
-        'Q' => vec!["CAG", "CAA"],
-        'R' => vec!["AGG", "AGA", "CGG", "CGA", "CGC", "CGU"],
-        'S' => vec!["AGC", "AGU", "UCG", "UCA", "UCC", "UCU"],
-        'T' => vec!["ACG", "ACA", "ACC", "ACU"],
-        'V' => vec!["GUU", "GUC", "GUA", "GUG"],
-        'W' => vec!["UGG"],
-        'Y' => vec!["UAC", "UAU"],
-        ' ' => vec!["UAG", "UGA", "UAA"],
-        _ => panic!("Invalid amino acid"),
+impl From<RNA> for DNA {
+    /// Convert an RNA strand into a DNA strand
+    ///
+    /// The case of each symbol is preserved, so lowercase (masked) regions of the strand remain
+    /// lowercase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `RNA_SYMBOLS`, in either case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("ACGUUGCA");
+    /// let dna = DNA::from(rna);  // "ACGTTGCA"
+    /// # assert_eq!(dna.content(), "ACGTTGCA");
+    /// ```
+    fn from(rna: RNA) -> Self {
+        rna.reverse_transcribe()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl TryFrom<String> for DNA {
+    type Error = GenError;
 
-    #[test]
-    fn it_counts_individual_symbols() {
-        let dna = DNA::new("AACGGGTTTT");
-        assert_eq!(count_character('A', dna.content()), 2);
-        assert_eq!(count_character('C', dna.content()), 1);
-        assert_eq!(count_character('G', dna.content()), 3);
-        assert_eq!(count_character('T', dna.content()), 4);
+    /// Validate and convert a string into a DNA strand
+    ///
+    /// This behaves as `DNA::try_new`. It is also used to validate deserialized values.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// # use std::convert::TryFrom;
+    /// DNA::try_from("ACGT".to_string());  // Ok("ACGT")
+    /// # assert_eq!(DNA::try_from("ACGT".to_string()), Ok(DNA::new("ACGT")));
+    /// DNA::try_from("ACGX".to_string());  // Err(InvalidDnaSymbol { symbol: 'X', index: 3 })
+    /// # assert_eq!(
+    /// #     DNA::try_from("ACGX".to_string()),
+    /// #     Err(GenError::InvalidDnaSymbol { symbol: 'X', index: 3 })
+    /// # );
+    /// ```
+    fn try_from(dna_string: String) -> Result<DNA, GenError> {
+        DNA::try_new(&dna_string)
     }
+}
 
-    #[test]
-    fn it_gets_complement_symbols() {
-        assert_eq!(DNA::complement('G'), 'C');
-        assert_eq!(DNA::complement('A'), 'T');
-        assert_eq!(DNA::complement('C'), 'G');
-        assert_eq!(DNA::complement('T'), 'A');
+impl fmt::Display for DNA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.content())
+    }
+}
+
+impl Index<usize> for DNA {
+    type Output = str;
+
+    /// Return the symbol at the given (0-based) position of a DNA strand, as a string slice
+    ///
+    /// Positions are counted in characters, as with `DNA::base_at`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is past the end of the strand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGT");
+    /// &dna[2];  // "G"
+    /// # assert_eq!(&dna[2], "G");
+    /// ```
+    fn index(&self, index: usize) -> &str {
+        let content = self.content();
+
+        match content.char_indices().nth(index) {
+            Some((start, symbol)) => &content[start..start + symbol.len_utf8()],
+            None => panic!(
+                "Index {} is out of range for a DNA strand of length {}",
+                index,
+                self.length()
+            ),
+        }
+    }
+}
+
+impl AsRef<str> for DNA {
+    fn as_ref(&self) -> &str {
+        self.content()
+    }
+}
+
+impl Deref for DNA {
+    type Target = str;
+
+    /// Dereference a DNA struct to its content string
+    ///
+    /// This allows a `&DNA` to be passed where a `&str` is expected, and string methods to be
+    /// called on it directly.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("ACGT");
+    /// dna.starts_with("AC");  // true
+    /// # assert!(dna.starts_with("AC"));
+    /// rosalind::hamming_distance(&dna, &DNA::new("ACCT"));  // 1
+    /// # assert_eq!(rosalind::hamming_distance(&dna, &DNA::new("ACCT")), 1);
+    /// ```
+    fn deref(&self) -> &str {
+        self.content()
+    }
+}
+
+// RNA
+// --
+
+impl RNA {
+    /// Initialize and return a new RNA struct
+    ///
+    /// The symbols in the string are not validated. Use `RNA::try_new` when the input may contain
+    /// characters other than the `RNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::RNA;
+    /// let rna = RNA::new("ACGU");
+    /// ```
+    pub fn new(rna_string: &str) -> RNA {
+        RNA(String::from(rna_string.trim()))
+    }
+
+    /// Initialize and return a new RNA struct, validating each symbol
+    ///
+    /// Returns an error carrying the first character that is not one of the `RNA_SYMBOLS`, along
+    /// with its position in the trimmed string. An empty (or whitespace-only) string is rejected
+    /// with `GenError::EmptyStrand`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::try_new("ACGU");
+    /// # assert!(rna.is_ok());
+    /// let invalid = RNA::try_new("ACGT");  // Err(InvalidRnaSymbol { symbol: 'T', index: 3 })
+    /// # assert_eq!(invalid.err(), Some(GenError::InvalidRnaSymbol { symbol: 'T', index: 3 }));
+    /// ```
+    pub fn try_new(rna_string: &str) -> Result<RNA, GenError> {
+        let rna_string = rna_string.trim();
+
+        if rna_string.is_empty() {
+            return Err(GenError::EmptyStrand);
+        }
+
+        match rna_string
+            .chars()
+            .enumerate()
+            .find(|(_, symbol)| !RNA_SYMBOLS.contains(symbol))
+        {
+            Some((index, symbol)) => Err(GenError::InvalidRnaSymbol { symbol, index }),
+            None => Ok(RNA::new(rna_string)),
+        }
+    }
+
+    /// Generate an RNA strand of the given length, with each symbol chosen uniformly at random
+    /// from the `RNA_SYMBOLS`
+    ///
+    /// Requires the `rand` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::random(10, &mut rand::thread_rng());
+    /// rna.length();  // 10
+    /// # assert_eq!(rna.length(), 10);
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random(len: usize, rng: &mut impl Rng) -> RNA {
+        RNA(random_string(&RNA_SYMBOLS, len, rng))
+    }
+
+    /// Find every character of this RNA strand that is not one of the `RNA_SYMBOLS`
+    ///
+    /// Returns the 0-based position and the character of each one, in order. An empty vector
+    /// means that the strand is valid.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("ACGTUT");
+    /// rna.invalid_positions();  // [(3, 'T'), (5, 'T')]
+    /// # assert_eq!(rna.invalid_positions(), vec![(3, 'T'), (5, 'T')]);
+    /// ```
+    pub fn invalid_positions(&self) -> Vec<(usize, char)> {
+        invalid_positions(self.content(), |symbol| RNA_SYMBOLS.contains(&symbol))
+    }
+
+    /// Return a copy of this RNA strand with every symbol in uppercase
+    ///
+    /// The complement and transcription methods expect uppercase symbols. When reading strands
+    /// from a messy source, convert them to uppercase first and then validate them with
+    /// `RNA::try_new`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("acgun").to_uppercase();  // "ACGUN"
+    /// # assert_eq!(rna.content(), "ACGUN");
+    /// RNA::try_new(rna.content());  // Err(InvalidRnaSymbol { symbol: 'N', index: 4 })
+    /// # assert_eq!(
+    /// #     RNA::try_new(rna.content()).err(),
+    /// #     Some(GenError::InvalidRnaSymbol { symbol: 'N', index: 4 })
+    /// # );
+    /// ```
+    pub fn to_uppercase(&self) -> RNA {
+        RNA(self.content().to_uppercase())
+    }
+
+    /// Convert this RNA strand back into a DNA strand, without consuming it
+    ///
+    /// This is the same conversion as `DNA::from`, so the case of each symbol is preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `RNA_SYMBOLS`, in either case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("ACGUUGCA");
+    /// rna.reverse_transcribe();  // "ACGTTGCA"
+    /// # assert_eq!(rna.reverse_transcribe().content(), "ACGTTGCA");
+    /// ```
+    pub fn reverse_transcribe(&self) -> DNA {
+        let RNA(ref rna_string) = *self;
+
+        let dna_string = rna_string
+            .chars()
+            .enumerate()
+            .map(|(index, symbol)| get_dna_symbol(symbol, index))
+            .collect::<Result<String, _>>()
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        DNA::new(&dna_string)
+    }
+
+    /// Compute and return the reverse complement of an RNA strand
+    ///
+    /// The strand is reversed and each base is complemented, pairing `A` with `U` and `C` with
+    /// `G`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `RNA_SYMBOLS`. Use
+    /// `RNA::try_reverse_complement` to handle this case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AACGGU");
+    /// rna.reverse_complement();  // "ACCGUU"
+    /// # assert_eq!(rna.reverse_complement(), RNA::new("ACCGUU"));
+    /// ```
+    pub fn reverse_complement(&self) -> RNA {
+        Complement::reverse_complement(self)
+    }
+
+    /// Compute and return the reverse complement of an RNA strand, validating each symbol
+    ///
+    /// Returns `GenError::InvalidRnaSymbol` for the first character (in strand order) that is not
+    /// one of the `RNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AACGGU");
+    /// rna.try_reverse_complement();  // Ok("ACCGUU")
+    /// # assert_eq!(rna.try_reverse_complement(), Ok(RNA::new("ACCGUU")));
+    /// let invalid = RNA::new("AACGGT");
+    /// invalid.try_reverse_complement();  // Err(InvalidRnaSymbol { symbol: 'T', index: 5 })
+    /// # assert_eq!(
+    /// #     invalid.try_reverse_complement(),
+    /// #     Err(GenError::InvalidRnaSymbol { symbol: 'T', index: 5 })
+    /// # );
+    /// ```
+    pub fn try_reverse_complement(&self) -> Result<RNA, GenError> {
+        Complement::try_reverse_complement(self)
+    }
+
+    /// Count the perfect matchings of the base pair graph of an RNA strand
+    ///
+    /// In a perfect matching, every base is paired with a complementary base (`A` with `U`, and
+    /// `C` with `G`). This requires the strand to have as many `A` as `U` symbols and as many `C`
+    /// as `G` symbols, otherwise `GenError::UnpairedSymbols` is returned. The number of perfect
+    /// matchings is then `#A! * #C!`, which returns `GenError::Overflow` if it does not fit in a
+    /// `u128`. Use `RNA::perfect_matchings_big` (with the `bigint` feature) for longer strands.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AGCUAGUCAU");
+    /// rna.perfect_matchings();  // Ok(12)
+    /// # assert_eq!(rna.perfect_matchings(), Ok(12));
+    /// let unpaired = RNA::new("AGCUAGUCAA");
+    /// unpaired.perfect_matchings();  // Err(UnpairedSymbols('A', 'U'))
+    /// # assert_eq!(unpaired.perfect_matchings(), Err(GenError::UnpairedSymbols('A', 'U')));
+    /// ```
+    pub fn perfect_matchings(&self) -> Result<u128, GenError> {
+        let (au_pairs, cg_pairs) = self.base_pairs()?;
+
+        checked_factorial_u128(au_pairs)
+            .and_then(|au| checked_factorial_u128(cg_pairs).and_then(|cg| au.checked_mul(cg)))
+            .ok_or(GenError::Overflow)
+    }
+
+    /// Count the perfect matchings of the base pair graph of an RNA strand, without overflowing
+    ///
+    /// See `RNA::perfect_matchings`. Requires the `bigint` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "bigint")]
+    /// # {
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AGCUAGUCAU");
+    /// rna.perfect_matchings_big().unwrap().to_string();  // "12"
+    /// # assert_eq!(rna.perfect_matchings_big().unwrap().to_string(), "12");
+    /// # }
+    /// ```
+    #[cfg(feature = "bigint")]
+    pub fn perfect_matchings_big(&self) -> Result<BigUint, GenError> {
+        let (au_pairs, cg_pairs) = self.base_pairs()?;
+        Ok(factorial_big(au_pairs) * factorial_big(cg_pairs))
+    }
+
+    /// Iterate over the codons of an RNA strand
+    ///
+    /// Each codon is a slice of 3 symbols of the strand, so no allocation takes place. If the
+    /// length of the strand is not a multiple of 3, the remaining symbols are skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AUGGCCU");
+    /// rna.codons().collect::<Vec<_>>();  // ["AUG", "GCC"]
+    /// # assert_eq!(rna.codons().collect::<Vec<_>>(), vec!["AUG", "GCC"]);
+    /// ```
+    pub fn codons(&self) -> impl Iterator<Item = &str> {
+        split_codons(self.content())
+    }
+
+    /// Translate an RNA strand into a Protein string, recording where translation stopped
+    ///
+    /// Translation proceeds as for `Protein::from`. Along with the protein string, this returns
+    /// the 0-based codon index of the stop codon that ended translation, or `None` if the strand
+    /// ran out before a stop codon was reached.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AUGGCCUAAGCC");
+    /// rna.translate();  // ("MA", Some(2))
+    /// # assert_eq!(rna.translate(), (Protein::new("MA"), Some(2)));
+    /// RNA::new("AUGGCC").translate();  // ("MA", None)
+    /// # assert_eq!(RNA::new("AUGGCC").translate(), (Protein::new("MA"), None));
+    /// ```
+    pub fn translate(&self) -> (Protein, Option<usize>) {
+        let mut p_string = String::new();
+
+        for (index, codon) in self.codons().enumerate() {
+            match translate_codon(codon) {
+                Ok(Some(amino_acid)) => p_string.push(amino_acid),
+                Ok(None) => return (Protein::new(&p_string), Some(index)),
+                Err(_) => {}
+            }
+        }
+
+        (Protein::new(&p_string), None)
+    }
+
+    /// Count the codons of an RNA strand by the amino acid they encode
+    ///
+    /// Every codon of the strand is translated, including those after a stop codon, and the
+    /// counts are keyed on the amino acid, in alphabetical order. Stop codons are counted under
+    /// the `STOP_SYMBOL`. Codons that are not made up of `RNA_SYMBOLS` are not counted.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AUGGCCGCAUAA");
+    /// let usage = rna.codon_usage();  // {'*': 1, 'A': 2, 'M': 1}
+    /// # assert_eq!(
+    /// #     usage.into_iter().collect::<Vec<_>>(),
+    /// #     vec![('*', 1), ('A', 2), ('M', 1)]
+    /// # );
+    /// ```
+    pub fn codon_usage(&self) -> BTreeMap<char, usize> {
+        self.codons()
+            .filter_map(|codon| translate_codon(codon).ok())
+            .fold(BTreeMap::new(), |mut counts, amino_acid| {
+                *counts.entry(amino_acid.unwrap_or(STOP_SYMBOL)).or_insert(0) += 1;
+                counts
+            })
+    }
+
+    // Return the number of `A-U` and `C-G` pairs in a strand whose bases can all be paired
+    fn base_pairs(&self) -> Result<(u64, u64), GenError> {
+        let mut counts = [0u64; 4];
+
+        for (index, symbol) in self.content().chars().enumerate() {
+            match RNA_SYMBOLS.iter().position(|&x| x == symbol) {
+                Some(position) => counts[position] += 1,
+                None => return Err(GenError::InvalidRnaSymbol { symbol, index }),
+            }
+        }
+
+        let [a, c, g, u] = counts;
+        if a != u {
+            return Err(GenError::UnpairedSymbols('A', 'U'));
+        }
+        if c != g {
+            return Err(GenError::UnpairedSymbols('C', 'G'));
+        }
+
+        Ok((a, c))
+    }
+
+    // Return the complement of an RNA character, or `None` if it is not one of the `RNA_SYMBOLS`
+    fn complement_base(symbol: char) -> Option<char> {
+        RNA_SYMBOLS
+            .iter()
+            .rev()
+            .position(|&x| x == symbol)
+            .map(|position| RNA_SYMBOLS[position])
+    }
+}
+
+impl GeneticString for RNA {
+    fn content(&self) -> &str {
+        let RNA(ref content) = *self;
+        content
+    }
+}
+
+impl Complement for RNA {
+    fn complement_symbol(symbol: char) -> Option<char> {
+        RNA::complement_base(symbol)
+    }
+
+    fn invalid_symbol(symbol: char, index: usize) -> GenError {
+        GenError::InvalidRnaSymbol { symbol, index }
+    }
+
+    fn from_content(content: String) -> RNA {
+        RNA(content)
+    }
+}
+
+impl From<DNA> for RNA {
+    /// Convert a DNA strand into an RNA strand
+    ///
+    /// The case of each symbol is preserved, so lowercase (masked) regions of the strand remain
+    /// lowercase.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strand contains a symbol other than the `DNA_SYMBOLS`, in either case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let dna = DNA::new("CGTACGATCG");
+    /// let rna = RNA::from(dna);  // "CGUACGAUCG"
+    /// # assert_eq!(rna.content(), "CGUACGAUCG");
+    /// ```
+    fn from(dna: DNA) -> Self {
+        dna.transcribe()
+    }
+}
+
+impl TryFrom<String> for RNA {
+    type Error = GenError;
+
+    /// Validate and convert a string into a RNA strand
+    ///
+    /// This behaves as `RNA::try_new`. It is also used to validate deserialized values.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// # use std::convert::TryFrom;
+    /// RNA::try_from("ACGU".to_string());  // Ok("ACGU")
+    /// # assert_eq!(RNA::try_from("ACGU".to_string()), Ok(RNA::new("ACGU")));
+    /// RNA::try_from("ACGT".to_string());  // Err(InvalidRnaSymbol { symbol: 'T', index: 3 })
+    /// # assert_eq!(
+    /// #     RNA::try_from("ACGT".to_string()),
+    /// #     Err(GenError::InvalidRnaSymbol { symbol: 'T', index: 3 })
+    /// # );
+    /// ```
+    fn try_from(rna_string: String) -> Result<RNA, GenError> {
+        RNA::try_new(&rna_string)
+    }
+}
+
+impl fmt::Display for RNA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.content())
+    }
+}
+
+impl AsRef<str> for RNA {
+    fn as_ref(&self) -> &str {
+        self.content()
+    }
+}
+
+impl Deref for RNA {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.content()
+    }
+}
+
+// Protein
+// --
+
+impl Protein {
+    /// Initialize and return a new Protein string
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::Protein;
+    /// let protein = Protein::new("MTSMSS");
+    /// ```
+    pub fn new(protein_string: &str) -> Protein {
+        Protein(String::from(protein_string.trim()))
+    }
+
+    /// Initialize and return a new Protein struct, validating each amino acid
+    ///
+    /// Each character must be one of the `AMINO_ACID_SYMBOLS` or the `STOP_SYMBOL`. Returns
+    /// `GenError::InvalidAminoAcid` for the first character that is not, and
+    /// `GenError::EmptyStrand` for an empty (or whitespace-only) string.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::try_new("MTSMSS");
+    /// # assert!(protein.is_ok());
+    /// let invalid = Protein::try_new("MTBS");  // Err(InvalidAminoAcid('B'))
+    /// # assert_eq!(invalid.err(), Some(GenError::InvalidAminoAcid('B')));
+    /// ```
+    pub fn try_new(protein_string: &str) -> Result<Protein, GenError> {
+        let protein_string = protein_string.trim();
+
+        if protein_string.is_empty() {
+            return Err(GenError::EmptyStrand);
+        }
+
+        match protein_string.chars().find(|&ch| !is_amino_acid(ch)) {
+            Some(ch) => Err(GenError::InvalidAminoAcid(ch)),
+            None => Ok(Protein::new(protein_string)),
+        }
+    }
+
+    /// Return `true` if every character of this protein string is a standard amino acid
+    ///
+    /// The `STOP_SYMBOL` is also accepted. A protein string that is valid can be passed to
+    /// `Protein::rna_count` without panicking.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// Protein::new("MTSMSS*").is_valid();  // true
+    /// # assert!(Protein::new("MTSMSS*").is_valid());
+    /// Protein::new("MTBS").is_valid();  // false
+    /// # assert!(!Protein::new("MTBS").is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        is_valid_protein(self.content())
+    }
+
+    /// Find every character of this Protein string that is not an amino acid or the `STOP_SYMBOL`
+    ///
+    /// Returns the 0-based position and the character of each one, in order. An empty vector
+    /// means that the string is valid.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MTBSX*");
+    /// protein.invalid_positions();  // [(2, 'B'), (4, 'X')]
+    /// # assert_eq!(protein.invalid_positions(), vec![(2, 'B'), (4, 'X')]);
+    /// ```
+    pub fn invalid_positions(&self) -> Vec<(usize, char)> {
+        invalid_positions(self.content(), is_amino_acid)
+    }
+
+    /// Translate an RNA strand into a Protein string under the given genetic code
+    ///
+    /// As with `Protein::from`, translation stops at the first stop codon of the genetic code,
+    /// and codons that are not made up of `RNA_SYMBOLS` are skipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AUGUGAAAA");
+    /// Protein::from_rna_with_code(&rna, GeneticCode::Standard);  // "M"
+    /// # assert_eq!(Protein::from_rna_with_code(&rna, GeneticCode::Standard), Protein::new("M"));
+    /// Protein::from_rna_with_code(&rna, GeneticCode::VertebrateMitochondrial);  // "MWK"
+    /// # assert_eq!(
+    /// #     Protein::from_rna_with_code(&rna, GeneticCode::VertebrateMitochondrial),
+    /// #     Protein::new("MWK")
+    /// # );
+    /// ```
+    pub fn from_rna_with_code(rna: &RNA, code: GeneticCode) -> Protein {
+        let p_string = rna
+            .codons()
+            .map(|cd| code.translate_codon(cd))
+            .take_while(|amino_acid| *amino_acid != Ok(None))
+            .filter_map(|amino_acid| amino_acid.unwrap_or(None))
+            .collect::<String>();
+
+        Protein::new(&p_string)
+    }
+
+    /// Translate every codon of an RNA strand into a Protein string
+    ///
+    /// Unlike `Protein::from`, translation does not stop at the first stop codon. Stop codons are
+    /// skipped and translation continues with the next codon.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AAGUGUCUGGCUUGAAGU");
+    /// let protein = Protein::translate_all(&rna);  // "KCLAS"
+    /// # assert_eq!(protein.content(), "KCLAS");
+    /// ```
+    pub fn translate_all(rna: &RNA) -> Protein {
+        let p_string = rna.codons().filter_map(codon_table).collect::<String>();
+
+        Protein::new(&p_string)
+    }
+
+    /// Compute the monoisotopic mass of this protein string
+    ///
+    /// The mass is the sum of the monoisotopic masses of each amino acid residue in the string,
+    /// in daltons. Returns `GenError::InvalidAminoAcid` for the first invalid amino acid.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("SKADYEK");
+    /// protein.monoisotopic_mass().unwrap();  // 821.392
+    /// # assert!((protein.monoisotopic_mass().unwrap() - 821.392).abs() < 1e-3);
+    /// ```
+    pub fn monoisotopic_mass(&self) -> Result<f64, GenError> {
+        self.content()
+            .chars()
+            .try_fold(0f64, |acc, ch| Ok(acc + residue_mass(ch)?))
+    }
+
+    /// Determine the number of possible RNA strands that would form this protein string
+    ///
+    /// This is the same as `Protein::rna_source_count`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protein string contains an invalid amino acid. Use `Protein::try_rna_count`
+    /// to handle this case.
+    pub fn rna_count(&self, modulus: u32) -> Modulo {
+        self.rna_source_count(modulus)
+    }
+
+    /// Determine the number of possible RNA strands that would form this protein string
+    ///
+    /// The count is the product of the number of codons that encode each amino acid, multiplied
+    /// by the 3 possible stop codons that end every RNA strand. The stop codon is included
+    /// automatically, so a trailing `STOP_SYMBOL` in the protein string is not counted twice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protein string contains an invalid amino acid. Use `Protein::try_rna_count`
+    /// to handle this case.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MA");
+    /// protein.rna_source_count(1_000_000).remainder();  // 12 (1 * 4 * 3)
+    /// # assert_eq!(protein.rna_source_count(1_000_000).remainder(), 12);
+    /// ```
+    pub fn rna_source_count(&self, modulus: u32) -> Modulo {
+        self.try_rna_count(modulus)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Determine the number of possible RNA strands that would form this protein string
+    ///
+    /// See `Protein::rna_source_count`. Returns `GenError::InvalidAminoAcid` for the first
+    /// invalid amino acid in the string.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MA");
+    /// protein.try_rna_count(1_000_000).unwrap().remainder();  // 12
+    /// # assert_eq!(protein.try_rna_count(1_000_000).unwrap().remainder(), 12);
+    /// let invalid = Protein::new("MB");
+    /// invalid.try_rna_count(1_000_000);  // Err(InvalidAminoAcid('B'))
+    /// # assert_eq!(invalid.try_rna_count(1_000_000).err(), Some(GenError::InvalidAminoAcid('B')));
+    /// ```
+    pub fn try_rna_count(&self, modulus: u32) -> Result<Modulo, GenError> {
+        let codon_count = |amino_acid| -> Result<Modulo, GenError> {
+            Ok(modulo!(
+                codons_for_amino_acid(amino_acid)?.len() as i32,
+                modulus
+            ))
+        };
+
+        // A trailing stop symbol is the stop codon that is counted below
+        let residues = self
+            .content()
+            .trim_end_matches(STOP_SYMBOL)
+            .chars()
+            .try_fold(modulo!(1, modulus), |acc, ch| {
+                acc.checked_mul(codon_count(ch)?)
+            })?;
+
+        // Every RNA strand ends with one of the stop codons
+        residues.checked_mul(codon_count(STOP_SYMBOL)?)
+    }
+
+    /// Count the number of times each amino acid appears in this protein string
+    ///
+    /// This is the same as `GeneticString::symbol_frequencies`. The counts are keyed on the amino
+    /// acid, in alphabetical order. Every character of the string is counted as-is, including
+    /// characters that are not standard amino acids. Use `Protein::is_valid` or
+    /// `Protein::try_new` to reject those first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MAMAPRR");
+    /// let counts = protein.amino_acid_counts();  // {'A': 2, 'M': 2, 'P': 1, 'R': 2}
+    /// # assert_eq!(
+    /// #     counts.into_iter().collect::<Vec<_>>(),
+    /// #     vec![('A', 2), ('M', 2), ('P', 1), ('R', 2)]
+    /// # );
+    /// ```
+    pub fn amino_acid_counts(&self) -> BTreeMap<char, usize> {
+        self.symbol_frequencies()
+    }
+
+    /// Return this protein string without any trailing `STOP_SYMBOL`s or whitespace
+    ///
+    /// Proteins that differ only by a trailing stop are equal once normalized.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MAST*");
+    /// protein.normalized();  // "MAST"
+    /// # assert_eq!(protein.normalized(), Protein::new("MAST"));
+    /// ```
+    pub fn normalized(&self) -> Protein {
+        Protein::new(
+            self.content()
+                .trim_end_matches(|ch: char| ch == STOP_SYMBOL || ch.is_whitespace()),
+        )
+    }
+
+    /// Convert each amino acid of this protein string to its three-letter code
+    ///
+    /// The `STOP_SYMBOL` is converted to `Ter`. Returns `GenError::InvalidAminoAcid` for the first
+    /// character that is not a standard amino acid.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MA");
+    /// protein.three_letter_codes();  // Ok(["Met", "Ala"])
+    /// # assert_eq!(
+    /// #     protein.three_letter_codes(),
+    /// #     Ok(vec![String::from("Met"), String::from("Ala")])
+    /// # );
+    /// let invalid = Protein::new("MXA");
+    /// invalid.three_letter_codes();  // Err(InvalidAminoAcid('X'))
+    /// # assert_eq!(invalid.three_letter_codes(), Err(GenError::InvalidAminoAcid('X')));
+    /// ```
+    pub fn three_letter_codes(&self) -> Result<Vec<String>, GenError> {
+        self.content()
+            .chars()
+            .map(|ch| {
+                three_letter_code(ch)
+                    .map(String::from)
+                    .ok_or(GenError::InvalidAminoAcid(ch))
+            })
+            .collect()
+    }
+
+    /// Build one RNA strand that translates into this protein string
+    ///
+    /// Each amino acid is encoded by the first of its codons listed by `codons_for_amino_acid`,
+    /// and the strand ends with a stop codon. A trailing `STOP_SYMBOL` in the protein string
+    /// becomes that stop codon. Returns `GenError::InvalidAminoAcid` for the first invalid amino
+    /// acid.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let protein = Protein::new("MA");
+    /// let rna = protein.sample_rna().unwrap();  // "AUGGCUUAG"
+    /// # assert_eq!(rna.content(), "AUGGCUUAG");
+    /// Protein::from(rna);  // "MA"
+    /// # assert_eq!(Protein::from(protein.sample_rna().unwrap()), protein);
+    /// ```
+    pub fn sample_rna(&self) -> Result<RNA, GenError> {
+        let mut rna_string = self
+            .content()
+            .trim_end_matches(STOP_SYMBOL)
+            .chars()
+            .map(|ch| codons_for_amino_acid(ch).map(|codons| codons[0]))
+            .collect::<Result<String, _>>()?;
+        rna_string.push_str(codons_for_amino_acid(STOP_SYMBOL)?[0]);
+
+        Ok(RNA::new(&rna_string))
+    }
+
+    /// Find the positions of a motif in this protein string
+    ///
+    /// Returns the 1-based starting position of every match of the motif, including overlapping
+    /// matches.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let motif = ProteinMotif::try_new("N{P}[ST]{P}").unwrap();
+    /// let protein = Protein::new("MNNSTANGTP");
+    /// protein.find_motif(&motif);  // [2, 3]
+    /// # assert_eq!(protein.find_motif(&motif), vec![2, 3]);
+    /// ```
+    pub fn find_motif(&self, motif: &ProteinMotif) -> Vec<usize> {
+        motif
+            .find_all(self.content())
+            .into_iter()
+            .map(|index| index + 1)
+            .collect::<Vec<_>>()
+    }
+}
+
+impl GeneticString for Protein {
+    fn content(&self) -> &str {
+        let Protein(ref content) = *self;
+        content
+    }
+}
+
+impl From<RNA> for Protein {
+    /// Convert an RNA strand into a Protein string
+    ///
+    /// This is a one-way conversion due to the requirement that the RNA strand be divided into
+    /// chunks of 3. If the strand is not divisible by 3, the remaining characters are ignored.
+    /// Therefore, converting backwards from a protein string into an RNA strand may lack up to 2
+    /// characters that were present in the original RNA strand
+    ///
+    /// Translation stops at the first stop codon, ignoring the rest of the strand. Use
+    /// `Protein::translate_all` to translate every codon in the strand.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let rna = RNA::new("AAGUGUCUGGCUUGAAGU");
+    /// let protein = Protein::from(rna);  // "KCLA"
+    /// # assert_eq!(protein.content(), "KCLA");
+    /// ```
+    fn from(rna: RNA) -> Self {
+        Protein::from_rna_with_code(&rna, GeneticCode::Standard)
+    }
+}
+
+impl TryFrom<String> for Protein {
+    type Error = GenError;
+
+    /// Validate and convert a string into a Protein string
+    ///
+    /// This behaves as `Protein::try_new`. It is also used to validate deserialized values.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// # use std::convert::TryFrom;
+    /// Protein::try_from("MTSS".to_string());  // Ok("MTSS")
+    /// # assert_eq!(Protein::try_from("MTSS".to_string()), Ok(Protein::new("MTSS")));
+    /// Protein::try_from("MTBS".to_string());  // Err(InvalidAminoAcid('B'))
+    /// # assert_eq!(Protein::try_from("MTBS".to_string()), Err(GenError::InvalidAminoAcid('B')));
+    /// ```
+    fn try_from(protein_string: String) -> Result<Protein, GenError> {
+        Protein::try_new(&protein_string)
+    }
+}
+
+impl fmt::Display for Protein {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.content())
+    }
+}
+
+impl AsRef<str> for Protein {
+    fn as_ref(&self) -> &str {
+        self.content()
+    }
+}
+
+impl Deref for Protein {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.content()
+    }
+}
+
+// GeneticCode
+// --
+
+impl GeneticCode {
+    /// Return the NCBI identifier of this genetic code
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// GeneticCode::YeastMitochondrial.ncbi_id();  // 3
+    /// # assert_eq!(GeneticCode::YeastMitochondrial.ncbi_id(), 3);
+    /// ```
+    pub fn ncbi_id(self) -> u32 {
+        match self {
+            GeneticCode::Standard => 1,
+            GeneticCode::VertebrateMitochondrial => 2,
+            GeneticCode::YeastMitochondrial => 3,
+            GeneticCode::InvertebrateMitochondrial => 5,
+        }
+    }
+
+    /// Translate a single RNA codon into an amino acid under this genetic code
+    ///
+    /// As with `translate_codon`, returns `None` for the stop codons and `GenError::InvalidCodon`
+    /// if the codon is not a sequence of 3 `RNA_SYMBOLS`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let code = GeneticCode::VertebrateMitochondrial;
+    /// code.translate_codon("AUA");  // Ok(Some('M'))
+    /// # assert_eq!(code.translate_codon("AUA"), Ok(Some('M')));
+    /// code.translate_codon("AGA");  // Ok(None)
+    /// # assert_eq!(code.translate_codon("AGA"), Ok(None));
+    /// ```
+    pub fn translate_codon(self, codon: &str) -> Result<Option<char>, GenError> {
+        let amino_acid = match (self, codon) {
+            (GeneticCode::Standard, _) => return translate_codon(codon),
+            (_, "UGA") => Some('W'),
+            (_, "AUA") => Some('M'),
+            (GeneticCode::VertebrateMitochondrial, "AGA")
+            | (GeneticCode::VertebrateMitochondrial, "AGG") => None,
+            (GeneticCode::YeastMitochondrial, "CUU")
+            | (GeneticCode::YeastMitochondrial, "CUC")
+            | (GeneticCode::YeastMitochondrial, "CUA")
+            | (GeneticCode::YeastMitochondrial, "CUG") => Some('T'),
+            (GeneticCode::InvertebrateMitochondrial, "AGA")
+            | (GeneticCode::InvertebrateMitochondrial, "AGG") => Some('S'),
+            _ => return translate_codon(codon),
+        };
+
+        Ok(amino_acid)
+    }
+}
+
+// Motif
+// --
+
+impl Motif {
+    /// Parse a motif pattern
+    ///
+    /// Returns `GenError::InvalidMotif` if the pattern is empty, has an unclosed or empty group,
+    /// or contains a character that is not an uppercase letter.
+    pub fn try_new(pattern: &str) -> Result<Motif, GenError> {
+        let invalid = || GenError::InvalidMotif(pattern.to_string());
+        let mut positions: Vec<MotifPosition> = vec![];
+        let mut chars = pattern.trim().chars();
+
+        while let Some(ch) = chars.next() {
+            let position = match ch {
+                '[' => MotifPosition::AnyOf(motif_group(&mut chars, ']').ok_or_else(invalid)?),
+                '{' => MotifPosition::NoneOf(motif_group(&mut chars, '}').ok_or_else(invalid)?),
+                ch if ch.is_ascii_uppercase() => MotifPosition::Exact(ch),
+                _ => return Err(invalid()),
+            };
+            positions.push(position);
+        }
+
+        if positions.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Motif(positions))
+    }
+
+    /// Find the positions of this motif in a string
+    ///
+    /// Returns the 0-based starting position, counted in characters, of every match of the motif,
+    /// including overlapping matches.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let motif = compile_motif("A[CT]G");
+    /// motif.find_all("ATGACGAAG");  // [0, 3]
+    /// # assert_eq!(motif.find_all("ATGACGAAG"), vec![0, 3]);
+    /// ```
+    pub fn find_all(&self, text: &str) -> Vec<usize> {
+        let Motif(ref positions) = *self;
+        let symbols = text.chars().collect::<Vec<_>>();
+
+        symbols
+            .windows(positions.len())
+            .enumerate()
+            .filter(|(_, window)| {
+                window
+                    .iter()
+                    .zip(positions.iter())
+                    .all(|(&symbol, position)| position.matches(symbol))
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+    }
+}
+
+impl MotifPosition {
+    // Return `true` if the symbol matches this position
+    fn matches(&self, symbol: char) -> bool {
+        match *self {
+            MotifPosition::Exact(expected) => symbol == expected,
+            MotifPosition::AnyOf(ref group) => group.contains(&symbol),
+            MotifPosition::NoneOf(ref group) => !group.contains(&symbol),
+        }
+    }
+}
+
+// FASTA
+// --
+
+impl FASTA {
+    /// Initialize and return a new FASTA labelled genetic string
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let fasta = FASTA::new(DNA::new("ACGTTGCATC"), "DNA_1");
+    /// ```
+    pub fn new<T: GeneticString + 'static>(gen_string: T, label: &str) -> FASTA {
+        FASTA {
+            content: Box::new(gen_string),
+            label: String::from(label),
+        }
+    }
+
+    /// Get the label of this FASTA string
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let fasta = FASTA::new(DNA::new("ACGTTGCATC"), "DNA_1");
+    /// fasta.label(); // "DNA_1"
+    /// # assert_eq!(fasta.label(), "DNA_1");
+    /// ```
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// Format this FASTA string, wrapping the content at the given line width
+    ///
+    /// The label is written on the first line, prefixed with `>`. A line width of 0 writes the
+    /// whole content on a single line.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let fasta = FASTA::new(DNA::new("ACGTTGCATC"), "DNA_1");
+    /// fasta.to_fasta_string(4);  // ">DNA_1\nACGT\nTGCA\nTC"
+    /// # assert_eq!(fasta.to_fasta_string(4), ">DNA_1\nACGT\nTGCA\nTC");
+    /// ```
+    pub fn to_fasta_string(&self, line_width: usize) -> String {
+        let symbols = self.content().chars().collect::<Vec<_>>();
+        let lines = if line_width == 0 {
+            vec![self.content().to_string()]
+        } else {
+            symbols
+                .chunks(line_width)
+                .map(|line| line.iter().collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        format!(">{}\n{}", self.label, lines.join("\n"))
+    }
+
+    /// Parse a list of FASTA labelled DNA strings
+    ///
+    /// Each record starts with a `>` header line. The first whitespace-separated token of the
+    /// header is used as the label, and the lines following it, up to the next header, are joined
+    /// to form the DNA strand. Blank lines and Windows (`\r\n`) line endings are accepted.
+    ///
+    /// Returns `GenError::EmptyLabel` if a record has no label, or if there is content before the
+    /// first header.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let records = FASTA::parse_many(">DNA_1\nACGT\nACGT\n>DNA_2\nTTGCA\n").unwrap();
+    /// records[0].label();    // "DNA_1"
+    /// # assert_eq!(records[0].label(), "DNA_1");
+    /// records[0].content();  // "ACGTACGT"
+    /// # assert_eq!(records[0].content(), "ACGTACGT");
+    /// records[1].content();  // "TTGCA"
+    /// # assert_eq!(records[1].content(), "TTGCA");
+    /// ```
+    pub fn parse_many(input: &str) -> Result<Vec<FASTA>, GenError> {
+        FastaReader::new(input.as_bytes()).collect()
+    }
+}
+
+impl GeneticString for FASTA {
+    fn content(&self) -> &str {
+        (*self.content).content()
+    }
+}
+
+impl fmt::Display for FASTA {
+    /// Format this FASTA string, wrapping the content every `FASTA_LINE_WIDTH` symbols
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// let fasta = FASTA::new(DNA::new("ACGT"), "DNA_1");
+    /// fasta.to_string();  // ">DNA_1\nACGT"
+    /// # assert_eq!(fasta.to_string(), ">DNA_1\nACGT");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_fasta_string(FASTA_LINE_WIDTH))
+    }
+}
+
+// Serialized form of a FASTA labelled string
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct FastaRecord {
+    label: String,
+    content: String,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for FASTA {
+    /// Serialize this FASTA string as a `{ label, content }` record
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FastaRecord {
+            label: self.label(),
+            content: self.content().to_string(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for FASTA {
+    /// Deserialize a `{ label, content }` record into a FASTA labelled DNA string
+    ///
+    /// The content is validated as for `DNA::try_new`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<FASTA, D::Error> {
+        let record = FastaRecord::deserialize(deserializer)?;
+        let dna = DNA::try_new(&record.content).map_err(de::Error::custom)?;
+        Ok(FASTA::new(dna, &record.label))
+    }
+}
+
+impl<R: BufRead> FastaReader<R> {
+    /// Initialize and return a new reader of FASTA labelled DNA strings
+    ///
+    /// # Example
+    /// ```rust
+    /// # use rosalind::gen_str::*;
+    /// use std::io::Cursor;
+    ///
+    /// let reader = FastaReader::new(Cursor::new(">DNA_1\nACGT\n>DNA_2\nTTGCA"));
+    /// ```
+    pub fn new(reader: R) -> FastaReader<R> {
+        FastaReader {
+            lines: reader.lines(),
+            header: None,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for FastaReader<R> {
+    type Item = Result<FASTA, GenError>;
+
+    fn next(&mut self) -> Option<Result<FASTA, GenError>> {
+        let mut dna_string = String::new();
+
+        for line in &mut self.lines {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return Some(Err(GenError::from(err))),
+            };
+            let line = line.trim();
+
+            if let Some(header_line) = line.strip_prefix('>') {
+                // The header of the next record ends the current one
+                if let Some(header) = self.header.replace(header_line.to_string()) {
+                    return Some(fasta_from_parts(&header, &dna_string));
+                }
+            } else if !line.is_empty() {
+                if self.header.is_none() {
+                    return Some(Err(GenError::EmptyLabel));
+                }
+                dna_string.push_str(line);
+            }
+        }
+
+        // The last record ends with the input
+        self.header
+            .take()
+            .map(|header| fasta_from_parts(&header, &dna_string))
+    }
+}
+
+// GenError
+// --
+
+impl fmt::Display for GenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GenError::InvalidDnaSymbol { symbol, index } => {
+                write!(f, "Invalid DNA symbol '{}' at position {}", symbol, index)
+            }
+            GenError::InvalidRnaSymbol { symbol, index } => {
+                write!(f, "Invalid RNA symbol '{}' at position {}", symbol, index)
+            }
+            GenError::InvalidAminoAcid(symbol) => write!(f, "Invalid amino acid '{}'", symbol),
+            GenError::MismatchedModulus { left, right } => {
+                write!(f, "Mismatched moduli: {} and {}", left, right)
+            }
+            GenError::EmptyStrand => write!(f, "Empty strand"),
+            GenError::EmptyLabel => write!(f, "Missing FASTA label"),
+            GenError::Io(ref message) => write!(f, "I/O error: {}", message),
+            GenError::MismatchedLength { left, right } => {
+                write!(
+                    f,
+                    "Mismatched strand lengths: all strands must have the same length, found \
+                     lengths {} and {}",
+                    left, right
+                )
+            }
+            GenError::InvalidMotif(ref motif) => write!(f, "Invalid motif '{}'", motif),
+            GenError::InvalidCodon(ref codon) => write!(f, "Invalid codon '{}'", codon),
+            GenError::InvalidGenotype(ref genotype) => write!(f, "Invalid genotype '{}'", genotype),
+            GenError::UnpairedSymbols(symbol, complement) => write!(
+                f,
+                "Unpaired symbols: the counts of '{}' and '{}' differ",
+                symbol, complement
+            ),
+            GenError::Overflow => write!(f, "Numeric overflow"),
+        }
+    }
+}
+
+impl Error for GenError {}
+
+impl From<io::Error> for GenError {
+    fn from(err: io::Error) -> Self {
+        GenError::Io(err.to_string())
+    }
+}
+
+// ///////// //
+// Functions //
+// ///////// //
+
+/// Read the first FASTA labelled DNA string from a buffered source
+///
+/// This accepts any buffered source, such as a file or standard input, so a single record can be
+/// read the same way from either. A record may span several lines, and any records after the
+/// first are not read. Returns `GenError::EmptyStrand` if the source holds no record.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// use std::io::Cursor;
+///
+/// let fasta = read_single_fasta(Cursor::new(">DNA_1\nACGT\nTTGCA\n")).unwrap();
+/// fasta.label();  // "DNA_1"
+/// # assert_eq!(fasta.label(), "DNA_1");
+/// fasta.content();  // "ACGTTTGCA"
+/// # assert_eq!(fasta.content(), "ACGTTTGCA");
+/// ```
+pub fn read_single_fasta(reader: impl BufRead) -> Result<FASTA, GenError> {
+    FastaReader::new(reader)
+        .next()
+        .unwrap_or(Err(GenError::EmptyStrand))
+}
+
+/// Return `true` if a string is made up only of the `DNA_SYMBOLS`
+///
+/// The string is checked byte by byte, stopping at the first invalid character, so no
+/// intermediate strand or collection is built. An empty string is valid.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// is_valid_dna("ACGTTGCA");  // true
+/// # assert!(is_valid_dna("ACGTTGCA"));
+/// is_valid_dna("ACGU");  // false
+/// # assert!(!is_valid_dna("ACGU"));
+/// ```
+pub fn is_valid_dna(s: &str) -> bool {
+    s.bytes()
+        .all(|byte| matches!(byte, b'A' | b'C' | b'G' | b'T'))
+}
+
+/// Return `true` if a string is made up only of the `RNA_SYMBOLS`
+///
+/// As with `is_valid_dna`, this stops at the first invalid character.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// is_valid_rna("ACGUUGCA");  // true
+/// # assert!(is_valid_rna("ACGUUGCA"));
+/// is_valid_rna("ACGT");  // false
+/// # assert!(!is_valid_rna("ACGT"));
+/// ```
+pub fn is_valid_rna(s: &str) -> bool {
+    s.bytes()
+        .all(|byte| matches!(byte, b'A' | b'C' | b'G' | b'U'))
+}
+
+/// Return `true` if a string is made up only of the `AMINO_ACID_SYMBOLS` and the `STOP_SYMBOL`
+///
+/// As with `is_valid_dna`, this stops at the first invalid character.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// is_valid_protein("MTSMSS*");  // true
+/// # assert!(is_valid_protein("MTSMSS*"));
+/// is_valid_protein("MTBS");  // false
+/// # assert!(!is_valid_protein("MTBS"));
+/// ```
+pub fn is_valid_protein(s: &str) -> bool {
+    s.bytes()
+        .all(|byte| byte.is_ascii() && is_amino_acid(char::from(byte)))
+}
+
+/// List every possible k-mer over the `DNA_SYMBOLS` in lexicographic order
+///
+/// Unlike `lexicographic_kmers`, a `k` of 0 returns an empty vector.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// dna_kmers(2);  // ["AA", "AC", "AG", "AT", "CA", ..., "TT"]
+/// # assert_eq!(dna_kmers(2).len(), 16);
+/// # assert_eq!(&dna_kmers(2)[..5], &["AA", "AC", "AG", "AT", "CA"]);
+/// ```
+pub fn dna_kmers(k: usize) -> Vec<String> {
+    if k == 0 {
+        return vec![];
+    }
+
+    lexicographic_kmers(&DNA_SYMBOLS, k)
+}
+
+/// Translate a single RNA codon into an amino acid
+///
+/// Returns `None` for the stop codons, which end translation rather than producing an amino acid,
+/// and `GenError::InvalidCodon` if the codon is not a sequence of 3 `RNA_SYMBOLS`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// translate_codon("AUG");  // Ok(Some('M'))
+/// # assert_eq!(translate_codon("AUG"), Ok(Some('M')));
+/// translate_codon("UAA");  // Ok(None)
+/// # assert_eq!(translate_codon("UAA"), Ok(None));
+/// translate_codon("AUX");  // Err(InvalidCodon("AUX"))
+/// # assert_eq!(translate_codon("AUX"), Err(GenError::InvalidCodon("AUX".to_string())));
+/// ```
+pub fn translate_codon(codon: &str) -> Result<Option<char>, GenError> {
+    let amino_acid = match codon {
+        "GGU" | "GGC" | "GGA" | "GGG" => Some('G'),
+        "GUU" | "GUC" | "GUA" | "GUG" => Some('V'),
+        "GCU" | "GCC" | "GCA" | "GCG" => Some('A'),
+        "ACG" | "ACA" | "ACC" | "ACU" => Some('T'),
+        "CGG" | "CGA" | "CGC" | "CGU" | "AGG" | "AGA" => Some('R'),
+        "CUG" | "CUA" | "CUC" | "CUU" | "UUG" | "UUA" => Some('L'),
+        "CCG" | "CCA" | "CCC" | "CCU" => Some('P'),
+        "UCG" | "UCA" | "UCC" | "UCU" | "AGC" | "AGU" => Some('S'),
+        "AUA" | "AUC" | "AUU" => Some('I'),
+        "UAG" | "UGA" | "UAA" => None,
+        "GAU" | "GAC" => Some('D'),
+        "GAA" | "GAG" => Some('E'),
+        "AAU" | "AAC" => Some('N'),
+        "AAA" | "AAG" => Some('K'),
+        "CAC" | "CAU" => Some('H'),
+        "CAG" | "CAA" => Some('Q'),
+        "UUC" | "UUU" => Some('F'),
+        "UAC" | "UAU" => Some('Y'),
+        "UGC" | "UGU" => Some('C'),
+        "AUG" => Some('M'),
+        "UGG" => Some('W'),
+        _ => return Err(GenError::InvalidCodon(codon.to_string())),
+    };
+
+    Ok(amino_acid)
+}
+
+/// List the RNA codons that encode an amino acid
+///
+/// Most amino acids are encoded by more than one codon. The stop codons are listed for the
+/// `STOP_SYMBOL`. Returns `GenError::InvalidAminoAcid` for any other character.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// codons_for_amino_acid('M');  // Ok(["AUG"])
+/// # assert_eq!(codons_for_amino_acid('M'), Ok(vec!["AUG"]));
+/// codons_for_amino_acid(STOP_SYMBOL).unwrap().len();  // 3
+/// # assert_eq!(codons_for_amino_acid(STOP_SYMBOL).unwrap().len(), 3);
+/// ```
+pub fn codons_for_amino_acid(amino_acid: char) -> Result<Vec<&'static str>, GenError> {
+    let codons = match amino_acid {
+        'A' => vec!["GCU", "GCC", "GCA", "GCG"],
+        'C' => vec!["UGC", "UGU"],
+        'D' => vec!["GAU", "GAC"],
+        'E' => vec!["GAA", "GAG"],
+        'F' => vec!["UUC", "UUU"],
+        'G' => vec!["GGU", "GGC", "GGA", "GGG"],
+        'H' => vec!["CAC", "CAU"],
+        'I' => vec!["AUA", "AUC", "AUU"],
+        'K' => vec!["AAA", "AAG"],
+        'L' => vec!["UUG", "UUA", "CUG", "CUA", "CUC", "CUU"],
+        'M' => vec!["AUG"],
+        'N' => vec!["AAU", "AAC"],
+        'P' => vec!["CCG", "CCA", "CCC", "CCU"],
+        'Q' => vec!["CAG", "CAA"],
+        'R' => vec!["AGG", "AGA", "CGG", "CGA", "CGC", "CGU"],
+        'S' => vec!["AGC", "AGU", "UCG", "UCA", "UCC", "UCU"],
+        'T' => vec!["ACG", "ACA", "ACC", "ACU"],
+        'V' => vec!["GUU", "GUC", "GUA", "GUG"],
+        'W' => vec!["UGG"],
+        'Y' => vec!["UAC", "UAU"],
+        STOP_SYMBOL => vec!["UAG", "UGA", "UAA"],
+        _ => return Err(GenError::InvalidAminoAcid(amino_acid)),
+    };
+
+    Ok(codons)
+}
+
+/// Compute the profile matrix of a list of equal-length DNA strands
+///
+/// Each row of the matrix corresponds to one of the `DNA_SYMBOLS` (in the order `[A, C, G, T]`)
+/// and holds the number of times the symbol appears at each position across all the strands.
+/// Returns `GenError::MismatchedLength` if the strands are not all the same length. See
+/// `profile_matrix_generic` for other alphabets.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let strands = [DNA::new("ATCC"), DNA::new("AGCA"), DNA::new("TTCC")];
+/// let profile = profile_matrix(&strands).unwrap();
+/// &profile[0];  // [2, 0, 0, 1] (A)
+/// # assert_eq!(profile[0], vec![2, 0, 0, 1]);
+/// &profile[1];  // [0, 0, 3, 2] (C)
+/// # assert_eq!(profile[1], vec![0, 0, 3, 2]);
+/// ```
+pub fn profile_matrix(strands: &[DNA]) -> Result<[Vec<usize>; 4], GenError> {
+    let strings = strands
+        .iter()
+        .map(|strand| strand.content())
+        .collect::<Vec<_>>();
+    let mut rows = profile_matrix_generic(&strings, &DNA_SYMBOLS)?.into_iter();
+    let mut next_row = || rows.next().expect("One row per DNA symbol");
+
+    Ok([next_row(), next_row(), next_row(), next_row()])
+}
+
+/// Compute the profile matrix of a list of equal-length strings over the given alphabet
+///
+/// Each row of the matrix corresponds to a symbol of the alphabet, in the same order, and holds
+/// the number of times the symbol appears at each position across all the strings. Symbols that
+/// are not in the alphabet are not counted. Returns `GenError::MismatchedLength` if the strings
+/// are not all the same length.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let profile = profile_matrix_generic(&["AUG", "ACG"], &RNA_SYMBOLS).unwrap();
+/// &profile[3];  // [0, 1, 0] (U)
+/// # assert_eq!(profile[3], vec![0, 1, 0]);
+/// ```
+pub fn profile_matrix_generic(
+    seqs: &[&str],
+    alphabet: &[char],
+) -> Result<Vec<Vec<usize>>, GenError> {
+    let length = seqs.first().map_or(0, |seq| seq.chars().count());
+    let mut profile = vec![vec![0usize; length]; alphabet.len()];
+
+    for seq in seqs {
+        let seq_length = seq.chars().count();
+        if seq_length != length {
+            return Err(GenError::MismatchedLength {
+                left: length,
+                right: seq_length,
+            });
+        }
+
+        for (position, symbol) in seq.chars().enumerate() {
+            if let Some(row) = alphabet.iter().position(|&x| x == symbol) {
+                profile[row][position] += 1;
+            }
+        }
+    }
+
+    Ok(profile)
+}
+
+/// Build the consensus string of a profile matrix
+///
+/// The consensus string holds the most frequent DNA symbol at each position of the profile. Ties
+/// are resolved in favour of the lexicographically first symbol, following the order of the
+/// `DNA_SYMBOLS`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let strands = [DNA::new("ATCC"), DNA::new("AGCA"), DNA::new("TTCC")];
+/// let profile = profile_matrix(&strands).unwrap();
+/// consensus(&profile);  // "ATCC"
+/// # assert_eq!(consensus(&profile).content(), "ATCC");
+/// ```
+pub fn consensus(profile: &[Vec<usize>; 4]) -> DNA {
+    DNA::new(&consensus_generic(profile, &DNA_SYMBOLS))
+}
+
+/// Build the consensus string of a profile matrix over the given alphabet
+///
+/// Each row of the profile corresponds to the symbol of the alphabet in the same position, as
+/// returned by `profile_matrix_generic`. The consensus string holds the most frequent symbol at
+/// each position of the profile, with ties resolved in favour of the symbol that comes first in
+/// the alphabet.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let peptides = ["MKVL", "MRVL", "MKIL"];
+/// let profile = profile_matrix_generic(&peptides, &AMINO_ACID_SYMBOLS).unwrap();
+/// consensus_generic(&profile, &AMINO_ACID_SYMBOLS);  // "MKVL"
+/// # assert_eq!(consensus_generic(&profile, &AMINO_ACID_SYMBOLS), "MKVL");
+/// ```
+pub fn consensus_generic(profile: &[Vec<usize>], alphabet: &[char]) -> String {
+    let rows = alphabet.len().min(profile.len());
+    let length = profile[..rows]
+        .iter()
+        .map(|row| row.len())
+        .min()
+        .unwrap_or(0);
+
+    (0..length)
+        .map(|position| {
+            // `max_by_key` returns the last maximum, so the rows are searched in reverse
+            let row = (0..rows)
+                .rev()
+                .max_by_key(|&row| profile[row][position])
+                .unwrap();
+            alphabet[row]
+        })
+        .collect::<String>()
+}
+
+/// Build the overlap graph of a list of FASTA labelled strings
+///
+/// Returns the labels of every pair of records `(s, t)` where the suffix of length `k` of `s`
+/// matches the prefix of length `k` of `t`. A record is never paired with itself, and records
+/// shorter than `k` (or any record, when `k` is 0) are left out of the graph.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let records = [
+///     FASTA::new(DNA::new("AAATAAA"), "Rosalind_0498"),
+///     FASTA::new(DNA::new("AAATTTT"), "Rosalind_2391"),
+///     FASTA::new(DNA::new("TTTTCCC"), "Rosalind_2323"),
+///     FASTA::new(DNA::new("AAATCCC"), "Rosalind_0442"),
+///     FASTA::new(DNA::new("GGGTGGG"), "Rosalind_5013"),
+/// ];
+/// overlap_graph(&records, 3);
+/// // [("Rosalind_0498", "Rosalind_2391"), ("Rosalind_0498", "Rosalind_0442"),
+/// //  ("Rosalind_2391", "Rosalind_2323")]
+/// # assert_eq!(
+/// #     overlap_graph(&records, 3),
+/// #     vec![
+/// #         ("Rosalind_0498".to_string(), "Rosalind_2391".to_string()),
+/// #         ("Rosalind_0498".to_string(), "Rosalind_0442".to_string()),
+/// #         ("Rosalind_2391".to_string(), "Rosalind_2323".to_string()),
+/// #     ]
+/// # );
+/// ```
+pub fn overlap_graph(records: &[FASTA], k: usize) -> Vec<(String, String)> {
+    let mut edges: Vec<(String, String)> = vec![];
+
+    for (i, source) in records.iter().enumerate() {
+        let suffix = match string_suffix(source.content(), k) {
+            Some(suffix) => suffix,
+            None => continue,
+        };
+
+        for (j, target) in records.iter().enumerate() {
+            if i != j && string_prefix(target.content(), k) == Some(suffix) {
+                edges.push((source.label(), target.label()));
+            }
+        }
+    }
+
+    edges
+}
+
+/// Find the restriction sites in a FASTA labelled DNA string
+///
+/// Restriction sites are the reverse palindromes found by `DNA::reverse_palindromes`, with
+/// lengths between `min_len` and `max_len` (inclusive). Unlike that method, the positions returned
+/// are 1-based, as expected by the Rosalind REVP problem. Each site is given as its position and
+/// length. Symbols other than the `DNA_SYMBOLS` never form part of a site.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let record = FASTA::new(DNA::new("TCAATGCATGCGGGTCTATATGCAT"), "Rosalind_24");
+/// let sites = restriction_sites(&record, 4, 12);
+/// &sites[..3];  // [(4, 6), (5, 4), (6, 6)]
+/// # assert_eq!(&sites[..3], &[(4, 6), (5, 4), (6, 6)]);
+/// ```
+pub fn restriction_sites(record: &FASTA, min_len: usize, max_len: usize) -> Vec<(usize, usize)> {
+    DNA::new(record.content())
+        .reverse_palindromes(min_len, max_len)
+        .into_iter()
+        .map(|(position, length)| (position + 1, length))
+        .collect::<Vec<_>>()
+}
+
+/// Assemble a list of reads into the shortest superstring that contains them all
+///
+/// Each read is glued to the read whose prefix overlaps its suffix by more than half of the
+/// length of both reads. Returns `None` if there are no reads, or if the overlaps do not link
+/// every read into a single chain in a unique way.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let reads = [
+///     DNA::new("ATTAGACCTG"),
+///     DNA::new("CCTGCCGGAA"),
+///     DNA::new("AGACCTGCCG"),
+///     DNA::new("GCCGGAATAC"),
+/// ];
+/// assemble_reads(&reads);  // Some("ATTAGACCTGCCGGAATAC")
+/// # assert_eq!(assemble_reads(&reads), Some(DNA::new("ATTAGACCTGCCGGAATAC")));
+/// ```
+pub fn assemble_reads(reads: &[DNA]) -> Option<DNA> {
+    let mut successors: Vec<Option<(usize, usize)>> = vec![None; reads.len()];
+    let mut has_predecessor = vec![false; reads.len()];
+
+    for (i, first) in reads.iter().enumerate() {
+        for (j, other) in reads.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            if let Some(overlap) = read_overlap(first.content(), other.content()) {
+                // A read with several successors or predecessors can be assembled in several ways
+                if successors[i].is_some() || has_predecessor[j] {
+                    return None;
+                }
+                successors[i] = Some((j, overlap));
+                has_predecessor[j] = true;
+            }
+        }
+    }
+
+    let mut starts = (0..reads.len()).filter(|&i| !has_predecessor[i]);
+    let (start, other_start) = (starts.next()?, starts.next());
+    if other_start.is_some() {
+        return None;
+    }
+
+    let mut superstring = reads[start].content().to_string();
+    let mut current = start;
+    let mut assembled = 1usize;
+
+    while let Some((next, overlap)) = successors[current] {
+        superstring.extend(reads[next].content().chars().skip(overlap));
+        current = next;
+        assembled += 1;
+    }
+
+    if assembled == reads.len() {
+        Some(DNA::new(&superstring))
+    } else {
+        None
+    }
+}
+
+/// Find a longest substring shared by all the given DNA strands
+///
+/// If several substrings of the longest length are shared, the lexicographically smallest one is
+/// returned. Returns `None` if there are no strands or if they share no symbols.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let strands = [DNA::new("GATTACA"), DNA::new("TAGACCA"), DNA::new("ATACA")];
+/// longest_common_substring(&strands);  // Some("AC")
+/// # assert_eq!(longest_common_substring(&strands), Some("AC".to_string()));
+/// ```
+pub fn longest_common_substring(strands: &[DNA]) -> Option<String> {
+    let shortest = strands.iter().min_by_key(|strand| strand.length())?;
+
+    // Binary search on the substring length, since a shared substring of a given length implies
+    // shared substrings of every shorter length
+    let mut longest: Option<String> = None;
+    let (mut low, mut high) = (1usize, shortest.length());
+
+    while low <= high {
+        let length = (low + high) / 2;
+        let mut candidates = substrings_of_length(shortest.content(), length);
+
+        for strand in strands {
+            let substrings = substrings_of_length(strand.content(), length);
+            candidates.retain(|candidate| substrings.contains(candidate));
+        }
+
+        match candidates.iter().min() {
+            Some(candidate) => {
+                longest = Some(candidate.to_string());
+                low = length + 1;
+            }
+            None => high = length - 1,
+        }
+    }
+
+    longest
+}
+
+/// Compute the transition/transversion ratio of two DNA strands
+///
+/// Transitions are point mutations between the purines (`A` and `G`) or between the pyrimidines
+/// (`C` and `T`). Every other point mutation is a transversion. Returns
+/// `GenError::MismatchedLength` if the strands are not the same length.
+///
+/// If there are no transversions, the ratio is `f64::INFINITY` when there is at least one
+/// transition, and `0.0` when the strands are identical.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let first = DNA::new("GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT");
+/// let other = DNA::new("TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT");
+/// transition_transversion_ratio(&first, &other).unwrap();  // 1.21428571429
+/// # assert!((transition_transversion_ratio(&first, &other).unwrap() - 1.21428571429).abs() < 1e-9);
+/// ```
+pub fn transition_transversion_ratio(first: &DNA, other: &DNA) -> Result<f64, GenError> {
+    if first.length() != other.length() {
+        return Err(GenError::MismatchedLength {
+            left: first.length(),
+            right: other.length(),
+        });
+    }
+
+    let (transitions, transversions) = first
+        .content()
+        .chars()
+        .zip(other.content().chars())
+        .filter(|pair| pair.0 != pair.1)
+        .fold(
+            (0u32, 0u32),
+            |(transitions, transversions), pair| match pair {
+                ('A', 'G') | ('G', 'A') | ('C', 'T') | ('T', 'C') => {
+                    (transitions + 1, transversions)
+                }
+                _ => (transitions, transversions + 1),
+            },
+        );
+
+    match (transitions, transversions) {
+        (0, 0) => Ok(0f64),
+        (_, 0) => Ok(f64::INFINITY),
+        _ => Ok(f64::from(transitions) / f64::from(transversions)),
+    }
+}
+
+/// Count FASTA records by the length of their strands
+///
+/// Each length falls in the bucket starting at the nearest multiple of `bucket_size` below it.
+/// The counts are keyed on the lower bound of each bucket, in increasing order, and buckets with
+/// no records are left out. A `bucket_size` of 0 (or 1) counts each distinct length separately.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let records = [
+///     FASTA::new(DNA::new("ACGT"), "DNA_1"),
+///     FASTA::new(DNA::new("ACGTACGTAC"), "DNA_2"),
+///     FASTA::new(DNA::new("ACGTACG"), "DNA_3"),
+/// ];
+/// let histogram = length_histogram(&records, 5);  // {0: 1, 5: 1, 10: 1}
+/// # assert_eq!(
+/// #     histogram.into_iter().collect::<Vec<_>>(),
+/// #     vec![(0, 1), (5, 1), (10, 1)]
+/// # );
+/// ```
+pub fn length_histogram(records: &[FASTA], bucket_size: usize) -> BTreeMap<usize, usize> {
+    let bucket_size = bucket_size.max(1);
+
+    records
+        .iter()
+        .fold(BTreeMap::new(), |mut histogram, record| {
+            let bucket = record.length() / bucket_size * bucket_size;
+            *histogram.entry(bucket).or_insert(0) += 1;
+            histogram
+        })
+}
+
+/// Compute the length statistics of a collection of FASTA records, such as an assembly
+///
+/// The N50 is found by adding up the lengths of the strands from longest to shortest: it is the
+/// length of the strand that brings the running total to at least half of the total length.
+/// Equivalently, it is the largest length `L` such that the strands of length `L` or longer cover
+/// at least half of the total length. The median of an even number of strands is the mean of the
+/// two middle lengths. Every statistic is 0 for an empty collection.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let records = [
+///     FASTA::new(DNA::new("ACGTACGTAC"), "contig_1"),
+///     FASTA::new(DNA::new("ACGTAC"), "contig_2"),
+///     FASTA::new(DNA::new("ACGT"), "contig_3"),
+/// ];
+/// let stats = assembly_stats(&records);
+/// stats.total_length;  // 20
+/// # assert_eq!(stats.total_length, 20);
+/// stats.n50;  // 10
+/// # assert_eq!(stats.n50, 10);
+/// ```
+pub fn assembly_stats(records: &[FASTA]) -> AssemblyStats {
+    let mut lengths = records
+        .iter()
+        .map(|record| record.length())
+        .collect::<Vec<_>>();
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+
+    let count = lengths.len();
+    let total_length = lengths.iter().sum::<usize>();
+
+    if count == 0 {
+        return AssemblyStats {
+            total_length,
+            count,
+            mean_length: 0f64,
+            median_length: 0f64,
+            n50: 0,
+        };
+    }
+
+    let median_length = if count % 2 == 0 {
+        (lengths[count / 2 - 1] + lengths[count / 2]) as f64 / 2f64
+    } else {
+        lengths[count / 2] as f64
+    };
+
+    // The running total reaches half of the total length at the latest on the last strand
+    let mut covered = 0usize;
+    let n50 = lengths
+        .iter()
+        .find(|&&length| {
+            covered += length;
+            covered * 2 >= total_length
+        })
+        .cloned()
+        .unwrap_or(0);
+
+    AssemblyStats {
+        total_length,
+        count,
+        mean_length: total_length as f64 / count as f64,
+        median_length,
+        n50,
+    }
+}
+
+/// Sort FASTA records by GC content, from highest to lowest
+///
+/// The sort is stable, so records with the same GC content keep their relative order. Empty
+/// records, whose GC content is undefined, are placed after every other record.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let mut records = [
+///     FASTA::new(DNA::new("ATAT"), "DNA_1"),
+///     FASTA::new(DNA::new("GCGA"), "DNA_2"),
+/// ];
+/// sort_by_gc(&mut records);
+/// records[0].label();  // "DNA_2"
+/// # assert_eq!(records[0].label(), "DNA_2");
+/// ```
+pub fn sort_by_gc(records: &mut [FASTA]) {
+    records.sort_by(|first, other| {
+        compare_gc(first.try_gc_content().ok(), other.try_gc_content().ok())
+    });
+}
+
+/// Rank FASTA records by GC content, from highest to lowest
+///
+/// Returns each record along with its GC content (as a percentage), in the same order as
+/// `sort_by_gc`, without reordering the records themselves. Empty records are given a GC content
+/// of `0.0` and placed last.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let records = [
+///     FASTA::new(DNA::new("ATAT"), "DNA_1"),
+///     FASTA::new(DNA::new("GCGA"), "DNA_2"),
+/// ];
+/// let ranked = ranked_by_gc(&records);
+/// (ranked[0].0.label(), ranked[0].1);  // ("DNA_2", 75.0)
+/// # assert_eq!((ranked[0].0.label(), ranked[0].1), ("DNA_2".to_string(), 75f64));
+/// ```
+pub fn ranked_by_gc(records: &[FASTA]) -> Vec<(&FASTA, f64)> {
+    let mut ranked = records
+        .iter()
+        .map(|record| (record, record.try_gc_content().ok()))
+        .collect::<Vec<_>>();
+    ranked.sort_by(|first, other| compare_gc(first.1, other.1));
+
+    ranked
+        .into_iter()
+        .map(|(record, gc)| (record, gc.unwrap_or(0f64)))
+        .collect::<Vec<_>>()
+}
+
+/// Compute the matrix of p-distances between a list of equal-length DNA strands
+///
+/// The p-distance between two strands is the proportion of positions at which their symbols
+/// differ. The resulting matrix is symmetric, with zeros on its diagonal. Returns
+/// `GenError::MismatchedLength` if the strands are not all the same length, and
+/// `GenError::EmptyStrand` if they are all empty.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let strands = [DNA::new("TTTCCATTTA"), DNA::new("GATTCATTTC"), DNA::new("TTTCCATTTT")];
+/// let matrix = p_distance_matrix(&strands).unwrap();
+/// &matrix[0];  // [0.0, 0.4, 0.1]
+/// # assert_eq!(matrix[0], vec![0.0, 0.4, 0.1]);
+/// ```
+pub fn p_distance_matrix(strands: &[DNA]) -> Result<Vec<Vec<f64>>, GenError> {
+    let length = strands.first().map_or(0, |strand| strand.length());
+
+    for strand in strands {
+        if strand.length() != length {
+            return Err(GenError::MismatchedLength {
+                left: length,
+                right: strand.length(),
+            });
+        }
+    }
+
+    if length == 0 && !strands.is_empty() {
+        return Err(GenError::EmptyStrand);
+    }
+
+    Ok(strands
+        .iter()
+        .map(|first| {
+            strands
+                .iter()
+                .map(|other| hamming_distance(first, other) as f64 / length as f64)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>())
+}
+
+/// Find the candidate strand closest to a query strand by Hamming distance
+///
+/// Candidates that are not the same length as the query are skipped, since their Hamming distance
+/// is undefined. Returns the closest candidate with its distance, or `None` if no candidate has
+/// the same length as the query. If several candidates are equally close, the first is returned.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let query = DNA::new("ACGT");
+/// let candidates = [DNA::new("TTTT"), DNA::new("ACGA"), DNA::new("ACG")];
+/// let (nearest, distance) = nearest_by_hamming(&query, &candidates).unwrap();  // (ACGA, 1)
+/// # assert_eq!((nearest, distance), (&candidates[1], 1));
+/// ```
+pub fn nearest_by_hamming<'a>(query: &DNA, candidates: &'a [DNA]) -> Option<(&'a DNA, usize)> {
+    candidates
+        .iter()
+        .filter(|candidate| candidate.length() == query.length())
+        .map(|candidate| (candidate, hamming_distance(query, candidate)))
+        .min_by_key(|&(_, distance)| distance)
+}
+
+/// Compile a motif pattern
+///
+/// See `Motif` for the pattern syntax.
+///
+/// # Panics
+///
+/// Panics if the pattern is not a valid motif. Use `Motif::try_new` to handle this case.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let motif = compile_motif("N{P}[ST]{P}");
+/// motif.find_all("MNNSTANGTP");  // [1, 2]
+/// # assert_eq!(motif.find_all("MNNSTANGTP"), vec![1, 2]);
+/// ```
+pub fn compile_motif(pattern: &str) -> Motif {
+    Motif::try_new(pattern).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Correct single-symbol errors in a list of sequencing reads
+///
+/// A read is correct if it appears at least twice in the list, either as itself or as its reverse
+/// complement. Every other read is matched against the correct reads and their reverse
+/// complements, and is corrected to the one it differs from by a single symbol. Returns a
+/// `(wrong, corrected)` pair for each read that can be corrected, in the order of the reads.
+/// Reads with no such match, or with several, are left out.
+///
+/// # Panics
+///
+/// Panics if a read contains a symbol other than the `DNA_SYMBOLS`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let reads = ["TCATC", "TTCAT", "TCATC", "TGAAA", "GAGGA", "TTTCA", "ATCAA", "TTGAT", "TTTCC"]
+///     .iter()
+///     .map(|read| DNA::new(read))
+///     .collect::<Vec<_>>();
+/// let corrections = correct_reads(&reads);
+/// &corrections[1];  // ("GAGGA", "GATGA")
+/// # assert_eq!(corrections[1], (DNA::new("GAGGA"), DNA::new("GATGA")));
+/// ```
+pub fn correct_reads(reads: &[DNA]) -> Vec<(DNA, DNA)> {
+    let complements = reads
+        .iter()
+        .map(|read| read.reverse_complement())
+        .collect::<Vec<_>>();
+    let is_correct = |read: &DNA, complement: &DNA| {
+        reads
+            .iter()
+            .filter(|&other| other == read || other == complement)
+            .count()
+            >= 2
+    };
+
+    let mut correct: Vec<&DNA> = vec![];
+    let mut incorrect: Vec<&DNA> = vec![];
+    for (read, complement) in reads.iter().zip(complements.iter()) {
+        if !is_correct(read, complement) {
+            incorrect.push(read);
+        } else if !correct.contains(&read) {
+            correct.push(read);
+            if complement != read {
+                correct.push(complement);
+            }
+        }
+    }
+
+    incorrect
+        .into_iter()
+        .filter_map(|read| {
+            let mut matches = correct.iter().filter(|candidate| {
+                candidate.length() == read.length() && hamming_distance(read, candidate) == 1
+            });
+
+            match (matches.next(), matches.next()) {
+                (Some(&corrected), None) => Some((read.clone(), corrected.clone())),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+/// Compute the edit distance between two strings
+///
+/// The edit distance (or Levenshtein distance) is the minimum number of single-character
+/// insertions, deletions and substitutions required to convert one string to another. Unlike the
+/// Hamming distance, the strings need not be the same length. Only two rows of the dynamic
+/// programming table are kept, so memory use is linear in the length of the second string.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// edit_distance("PLEASANTLY", "MEANLY");  // 5
+/// # assert_eq!(edit_distance("PLEASANTLY", "MEANLY"), 5);
+/// ```
+pub fn edit_distance(first: &str, other: &str) -> usize {
+    let other = other.chars().collect::<Vec<_>>();
+    let mut previous = (0..=other.len()).collect::<Vec<_>>();
+    let mut current = vec![0usize; other.len() + 1];
+
+    for (i, symbol) in first.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, &other_symbol) in other.iter().enumerate() {
+            let substitution = previous[j] + if symbol == other_symbol { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[other.len()]
+}
+
+/// Find the FASTA record with the highest GC content
+///
+/// Returns the record along with its GC content (as a percentage), or `None` if there are no
+/// records. If several records share the highest GC content, the first of them is returned.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::gen_str::*;
+/// let records = [
+///     FASTA::new(DNA::new("ATAT"), "DNA_1"),
+///     FASTA::new(DNA::new("GCGA"), "DNA_2"),
+///     FASTA::new(DNA::new("GCAA"), "DNA_3"),
+/// ];
+/// let (record, gc) = max_gc_content(&records).unwrap();  // (DNA_2, 75.0)
+/// # assert_eq!(record.label(), "DNA_2");
+/// # assert_eq!(gc, 75f64);
+/// ```
+pub fn max_gc_content(records: &[FASTA]) -> Option<(&FASTA, f64)> {
+    records
+        .iter()
+        .map(|record| (record, record.gc_content()))
+        .fold(None, |max, (record, gc)| match max {
+            Some((_, max_gc)) if max_gc >= gc => max,
+            _ => Some((record, gc)),
+        })
+}
+
+// Return the set of substrings with the given number of characters
+fn substrings_of_length(input: &str, length: usize) -> HashSet<&str> {
+    let mut boundaries = input.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+    boundaries.push(input.len());
+
+    boundaries
+        .windows(length + 1)
+        .map(|window| &input[window[0]..window[length]])
+        .collect::<HashSet<_>>()
+}
+
+// Order GC contents from highest to lowest, with undefined GC contents last
+fn compare_gc(first: Option<f64>, other: Option<f64>) -> Ordering {
+    match (first, other) {
+        (Some(first), Some(other)) => other.partial_cmp(&first).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+// Return the length of the longest suffix of `first` that is also a prefix of `other`, if it is
+// longer than half of both strings
+fn read_overlap(first: &str, other: &str) -> Option<usize> {
+    let (first_len, other_len) = (first.chars().count(), other.chars().count());
+
+    (1..=first_len.min(other_len))
+        .rev()
+        .take_while(|&length| length * 2 > first_len && length * 2 > other_len)
+        .find(|&length| string_suffix(first, length) == string_prefix(other, length))
+}
+
+// Return the first `length` characters of a string, if it is long enough and `length` is not 0
+fn string_prefix(input: &str, length: usize) -> Option<&str> {
+    if length == 0 {
+        return None;
+    }
+
+    match input.char_indices().nth(length) {
+        Some((end, _)) => Some(&input[..end]),
+        None if input.chars().count() == length => Some(input),
+        None => None,
+    }
+}
+
+// Return the last `length` characters of a string, if it is long enough and `length` is not 0
+fn string_suffix(input: &str, length: usize) -> Option<&str> {
+    if length == 0 {
+        return None;
+    }
+
+    input
+        .char_indices()
+        .rev()
+        .nth(length - 1)
+        .map(|(start, _)| &input[start..])
+}
+
+// Create a FASTA labelled DNA string from a header line (without the leading `>`) and a strand
+fn fasta_from_parts(header: &str, dna_string: &str) -> Result<FASTA, GenError> {
+    match header.split_whitespace().next() {
+        Some(label) => Ok(FASTA::new(DNA::new(dna_string), label)),
+        None => Err(GenError::EmptyLabel),
+    }
+}
+
+// Read the symbols of a motif group up to its closing character. Returns `None` if the group is
+// empty, unclosed or contains a character that is not an uppercase letter
+fn motif_group(chars: &mut std::str::Chars, close: char) -> Option<Vec<char>> {
+    let mut group: Vec<char> = vec![];
+
+    for ch in chars {
+        match ch {
+            ch if ch == close => return if group.is_empty() { None } else { Some(group) },
+            ch if ch.is_ascii_uppercase() => group.push(ch),
+            _ => return None,
+        }
+    }
+
+    None
+}
+
+// Return the position and character of every character of a string that is not valid
+fn invalid_positions(content: &str, is_valid: impl Fn(char) -> bool) -> Vec<(usize, char)> {
+    content
+        .chars()
+        .enumerate()
+        .filter(|&(_, symbol)| !is_valid(symbol))
+        .collect::<Vec<_>>()
+}
+
+// Return `true` if the character is a standard amino acid or the stop symbol
+fn is_amino_acid(symbol: char) -> bool {
+    symbol == STOP_SYMBOL || AMINO_ACID_SYMBOLS.contains(&symbol)
+}
+
+// Return the three-letter code of an amino acid, or `None` if the character is not one
+fn three_letter_code(amino_acid: char) -> Option<&'static str> {
+    match amino_acid {
+        'A' => Some("Ala"),
+        'C' => Some("Cys"),
+        'D' => Some("Asp"),
+        'E' => Some("Glu"),
+        'F' => Some("Phe"),
+        'G' => Some("Gly"),
+        'H' => Some("His"),
+        'I' => Some("Ile"),
+        'K' => Some("Lys"),
+        'L' => Some("Leu"),
+        'M' => Some("Met"),
+        'N' => Some("Asn"),
+        'P' => Some("Pro"),
+        'Q' => Some("Gln"),
+        'R' => Some("Arg"),
+        'S' => Some("Ser"),
+        'T' => Some("Thr"),
+        'V' => Some("Val"),
+        'W' => Some("Trp"),
+        'Y' => Some("Tyr"),
+        STOP_SYMBOL => Some("Ter"),
+        _ => None,
+    }
+}
+
+// Count the number of times a character occurs in the given string
+fn count_character(character: char, in_string: &str) -> usize {
+    in_string.chars().filter(|ch| *ch == character).count()
+}
+
+// Return the RNA symbol that corresponds to the given DNA symbol, in the same case. The index is
+// the position of the symbol in its strand, used for error reporting
+fn get_rna_symbol(symbol: char, index: usize) -> Result<char, GenError> {
+    DNA_SYMBOLS
+        .iter()
+        .position(|&x| x == symbol.to_ascii_uppercase())
+        .map(|position| with_case_of(RNA_SYMBOLS[position], symbol))
+        .ok_or(GenError::InvalidDnaSymbol { symbol, index })
+}
+
+// Return the DNA symbol that corresponds to the given RNA symbol, in the same case. The index is
+// the position of the symbol in its strand, used for error reporting
+fn get_dna_symbol(symbol: char, index: usize) -> Result<char, GenError> {
+    RNA_SYMBOLS
+        .iter()
+        .position(|&x| x == symbol.to_ascii_uppercase())
+        .map(|position| with_case_of(DNA_SYMBOLS[position], symbol))
+        .ok_or(GenError::InvalidRnaSymbol { symbol, index })
+}
+
+// Generate a string of the given length from symbols chosen uniformly at random
+#[cfg(feature = "rand")]
+fn random_string(symbols: &[char], len: usize, rng: &mut impl Rng) -> String {
+    (0..len)
+        .map(|_| *symbols.choose(rng).expect("Symbols are not empty"))
+        .collect::<String>()
+}
+
+// Return an uppercase symbol in the same case as the `original` symbol
+fn with_case_of(symbol: char, original: char) -> char {
+    if original.is_ascii_lowercase() {
+        symbol.to_ascii_lowercase()
+    } else {
+        symbol
+    }
+}
+
+// Split an RNA string into slices of 3 symbols, without allocating. A trailing chunk of fewer
+// than 3 symbols is skipped
+fn split_codons(rna_string: &str) -> impl Iterator<Item = &str> {
+    rna_string
+        .char_indices()
+        .map(|(index, _)| index)
+        .chain(iter::once(rna_string.len()))
+        .step_by(3)
+        .skip(1)
+        .scan(0usize, move |start, end| {
+            let codon = &rna_string[*start..end];
+            *start = end;
+            Some(codon)
+        })
+}
+
+// Return `true` if the given RNA codon is a stop codon
+fn is_stop_codon(rna_slice: &str) -> bool {
+    codons_for_amino_acid(STOP_SYMBOL)
+        .map(|stops| stops.contains(&rna_slice))
+        .unwrap_or(false)
+}
+
+// Return the amino acid produced by the given RNA codon, if any. Stop codons and invalid codons
+// produce no amino acid
+fn codon_table(rna_slice: &str) -> Option<char> {
+    translate_codon(rna_slice).unwrap_or(None)
+}
+
+// Return the monoisotopic mass of the given amino acid residue
+fn residue_mass(amino_acid: char) -> Result<f64, GenError> {
+    let mass = match amino_acid {
+        'A' => 71.03711,
+        'C' => 103.00919,
+        'D' => 115.02694,
+        'E' => 129.04259,
+        'F' => 147.06841,
+        'G' => 57.02146,
+        'H' => 137.05891,
+        'I' => 113.08406,
+        'K' => 128.09496,
+        'L' => 113.08406,
+        'M' => 131.04049,
+        'N' => 114.04293,
+        'P' => 97.05276,
+        'Q' => 128.05858,
+        'R' => 156.10111,
+        'S' => 87.03203,
+        'T' => 101.04768,
+        'V' => 99.06841,
+        'W' => 186.07931,
+        'Y' => 163.06333,
+        _ => return Err(GenError::InvalidAminoAcid(amino_acid)),
+    };
+
+    Ok(mass)
+}
+
+/// Serialization of `Modulo` values
+///
+/// `Modulo` is defined by the `modular` crate, so it cannot implement the serde traits directly.
+/// These functions serialize it as a `{ remainder, modulus }` record for use with
+/// `#[serde(with = "rosalind::gen_str::modulo_serde")]`. Requires the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod modulo_serde {
+    use modular::{modulo, Modular, Modulo};
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    // Serialized form of a modular value
+    #[derive(Serialize, Deserialize)]
+    struct ModuloRecord {
+        remainder: i32,
+        modulus: u32,
+    }
+
+    /// Serialize a `Modulo` as a `{ remainder, modulus }` record
+    pub fn serialize<S: Serializer>(value: &Modulo, serializer: S) -> Result<S::Ok, S::Error> {
+        ModuloRecord {
+            remainder: value.remainder(),
+            modulus: value.modulus(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a `{ remainder, modulus }` record into a `Modulo`
+    ///
+    /// Returns an error if the modulus is 0, or too large to be represented by `Modulo`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Modulo, D::Error> {
+        let record = ModuloRecord::deserialize(deserializer)?;
+
+        if record.modulus == 0 || record.modulus > i32::MAX as u32 {
+            return Err(de::Error::custom(format!(
+                "invalid modulus {}, expected a value from 1 to {}",
+                record.modulus,
+                i32::MAX
+            )));
+        }
+
+        Ok(modulo!(record.remainder, record.modulus))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_counts_individual_symbols() {
+        let dna = DNA::new("AACGGGTTTT");
+        assert_eq!(count_character('A', dna.content()), 2);
+        assert_eq!(count_character('C', dna.content()), 1);
+        assert_eq!(count_character('G', dna.content()), 3);
+        assert_eq!(count_character('T', dna.content()), 4);
+    }
+
+    #[test]
+    fn it_counts_all_symbols_in_a_single_pass() {
+        let dna = DNA::new(&"ACGGTAACTX".repeat(1000));
+        let expected = DNA_SYMBOLS
+            .iter()
+            .map(|symbol| count_character(*symbol, dna.content()))
+            .collect::<Vec<_>>();
+        assert_eq!(dna.count_symbols(), expected);
+        assert_eq!(dna.count_symbols(), vec![3000, 2000, 2000, 2000]);
+    }
+
+    #[test]
+    fn it_gets_complement_symbols() {
+        assert_eq!(DNA::complement('G'), 'C');
+        assert_eq!(DNA::complement('A'), 'T');
+        assert_eq!(DNA::complement('C'), 'G');
+        assert_eq!(DNA::complement('T'), 'A');
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid DNA string")]
+    fn it_only_complements_valid_symbols() {
+        assert_eq!(DNA::complement('Z'), 'Y');
+    }
+
+    #[test]
+    fn it_rejects_invalid_dna_symbols() {
+        assert_eq!(
+            DNA::try_new("acgt").err(),
+            Some(GenError::InvalidDnaSymbol {
+                symbol: 'a',
+                index: 0
+            })
+        );
+        assert_eq!(
+            DNA::try_new("  ACGUA ").err(),
+            Some(GenError::InvalidDnaSymbol {
+                symbol: 'U',
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn it_rejects_empty_dna_strands() {
+        assert_eq!(DNA::try_new("").err(), Some(GenError::EmptyStrand));
+        assert_eq!(DNA::try_new(" \n").err(), Some(GenError::EmptyStrand));
+    }
+
+    #[test]
+    fn it_rejects_invalid_rna_symbols() {
+        assert_eq!(
+            RNA::try_new("ACNU").err(),
+            Some(GenError::InvalidRnaSymbol {
+                symbol: 'N',
+                index: 2
+            })
+        );
+        assert_eq!(RNA::try_new("").err(), Some(GenError::EmptyStrand));
+    }
+
+    #[test]
+    fn it_reports_invalid_transcription_symbols() {
+        assert_eq!(get_rna_symbol('T', 0), Ok('U'));
+        assert_eq!(
+            get_rna_symbol('U', 3),
+            Err(GenError::InvalidDnaSymbol {
+                symbol: 'U',
+                index: 3
+            })
+        );
+        assert_eq!(get_dna_symbol('U', 0), Ok('T'));
+        assert_eq!(
+            get_dna_symbol('N', 1),
+            Err(GenError::InvalidRnaSymbol {
+                symbol: 'N',
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn it_stops_translation_at_the_first_stop_codon() {
+        // AUG GCC UAA GGA UGA
+        let rna = RNA::new("AUGGCCUAAGGAUGA");
+        assert_eq!(Protein::from(rna.clone()), Protein::new("MA"));
+        assert_eq!(Protein::translate_all(&rna).content(), "MAG");
+    }
+
+    #[test]
+    fn it_rejects_unknown_residues_when_computing_mass() {
+        assert_eq!(
+            Protein::new("SKXDYEK").monoisotopic_mass(),
+            Err(GenError::InvalidAminoAcid('X'))
+        );
+    }
+
+    #[test]
+    fn it_counts_kmers_in_lexicographic_order() {
+        let dna = DNA::new("CTTCGAAAGTTTGGACCCGAGCTTAGC");
+        let counts = dna.kmer_composition(3);
+        let kmers = dna_kmers(3);
+        assert_eq!(counts.len(), 64);
+        assert_eq!(counts.iter().sum::<usize>(), dna.length() - 2);
+        for (kmer, count) in kmers.iter().zip(counts.iter()) {
+            assert_eq!(*count, substring_count(dna.content(), kmer));
+        }
+        assert_eq!(dna.kmer_composition(0), Vec::<usize>::new());
+        assert_eq!(DNA::new("AC").kmer_composition(3), vec![0; 64]);
+    }
+
+    // Count possibly-overlapping occurences of a substring
+    fn substring_count(source: &str, substring: &str) -> usize {
+        (0..=(source.len() - substring.len()))
+            .filter(|i| source[*i..].starts_with(substring))
+            .count()
+    }
+
+    #[test]
+    fn it_parses_fasta_records() {
+        let input = "\r\n>Rosalind_1 first record\r\nACGT\r\n\r\nTTAA\r\n>Rosalind_2\r\nGGCC";
+        let records = FASTA::parse_many(input).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].label(), "Rosalind_1");
+        assert_eq!(records[0].content(), "ACGTTTAA");
+        assert_eq!(records[1].label(), "Rosalind_2");
+        assert_eq!(records[1].content(), "GGCC");
+    }
+
+    #[test]
+    fn it_rejects_fasta_records_without_labels() {
+        assert_eq!(
+            FASTA::parse_many(">\nACGT").err(),
+            Some(GenError::EmptyLabel)
+        );
+        assert_eq!(
+            FASTA::parse_many("ACGT\n>DNA_1\nACGT").err(),
+            Some(GenError::EmptyLabel)
+        );
+    }
+
+    #[test]
+    fn it_round_trips_fasta_records() {
+        let dna_string = "ACGT".repeat(40);
+        let fasta = FASTA::new(DNA::new(&dna_string), "Rosalind_1");
+        let text = fasta.to_string();
+        assert_eq!(text.lines().count(), 4);
+        assert!(text
+            .lines()
+            .skip(1)
+            .all(|line| line.len() <= FASTA_LINE_WIDTH));
+
+        let records = FASTA::parse_many(&text).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].label(), "Rosalind_1");
+        assert_eq!(records[0].content(), dna_string);
+    }
+
+    #[test]
+    fn it_builds_the_consensus_of_dna_strands() {
+        let strands = [
+            "ATCCAGCT", "GGGCAACT", "ATGGATCT", "AAGCAACC", "TTGGAACT", "ATGCCATT", "ATGGCACT",
+        ]
+        .iter()
+        .map(|strand| DNA::new(strand))
+        .collect::<Vec<_>>();
+        let profile = profile_matrix(&strands).unwrap();
+        assert_eq!(profile[0], vec![5, 1, 0, 0, 5, 5, 0, 0]);
+        assert_eq!(profile[1], vec![0, 0, 1, 4, 2, 0, 6, 1]);
+        assert_eq!(profile[2], vec![1, 1, 6, 3, 0, 1, 0, 0]);
+        assert_eq!(profile[3], vec![1, 5, 0, 0, 0, 1, 1, 6]);
+        assert_eq!(consensus(&profile).content(), "ATGCAACT");
+    }
+
+    #[test]
+    fn it_resolves_consensus_ties_lexicographically() {
+        let profile = profile_matrix(&[DNA::new("TG"), DNA::new("CG")]).unwrap();
+        assert_eq!(consensus(&profile).content(), "CG");
+    }
+
+    #[test]
+    fn it_builds_the_consensus_of_aligned_peptides() {
+        let peptides = ["MKVLA", "MRVLG", "AKILG"];
+        let profile = profile_matrix_generic(&peptides, &AMINO_ACID_SYMBOLS).unwrap();
+        assert_eq!(profile.len(), AMINO_ACID_SYMBOLS.len());
+        // M is the 11th of the amino acid symbols
+        assert_eq!(profile[10], vec![2, 0, 0, 0, 0]);
+        assert_eq!(consensus_generic(&profile, &AMINO_ACID_SYMBOLS), "MKVLG");
+
+        assert_eq!(
+            profile_matrix_generic(&["MKV", "MK"], &AMINO_ACID_SYMBOLS).err(),
+            Some(GenError::MismatchedLength { left: 3, right: 2 })
+        );
+    }
+
+    #[test]
+    fn it_rejects_profiles_of_unequal_strands() {
+        assert_eq!(
+            profile_matrix(&[DNA::new("ACGT"), DNA::new("ACG")]).err(),
+            Some(GenError::MismatchedLength { left: 4, right: 3 })
+        );
+    }
+
+    #[test]
+    fn it_excludes_self_and_short_overlaps() {
+        let records = [
+            FASTA::new(DNA::new("AAAA"), "repeat"),
+            FASTA::new(DNA::new("AA"), "short"),
+            FASTA::new(DNA::new("AAAT"), "other"),
+        ];
+        assert_eq!(
+            overlap_graph(&records, 3),
+            vec![("repeat".to_string(), "other".to_string())]
+        );
+        assert!(overlap_graph(&records, 0).is_empty());
+    }
+
+    #[test]
+    fn it_finds_the_longest_common_substring() {
+        let strands = [
+            DNA::new("TTACGTAC"),
+            DNA::new("GACGTT"),
+            DNA::new("CCACGTA"),
+        ];
+        assert_eq!(longest_common_substring(&strands), Some("ACGT".to_string()));
+        assert_eq!(
+            longest_common_substring(&[DNA::new("AAAA"), DNA::new("CCCC")]),
+            None
+        );
+        assert_eq!(longest_common_substring(&[]), None);
     }
 
     #[test]
-    #[should_panic(expected = "Invalid DNA string")]
-    fn it_only_complements_valid_symbols() {
-        assert_eq!(DNA::complement('Z'), 'Y');
+    fn it_finds_open_reading_frames() {
+        let dna = DNA::new(
+            "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG",
+        );
+        let mut proteins = dna
+            .open_reading_frames()
+            .iter()
+            .map(|protein| protein.content().to_string())
+            .collect::<Vec<_>>();
+        proteins.sort();
+        assert_eq!(
+            proteins,
+            vec![
+                "M",
+                "MGMTPRLGLESLLE",
+                "MLLGSFRLIPKETLIQVAGSSPCNLS",
+                "MTPRLGLESLLE"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_finds_reverse_palindromes() {
+        let dna = DNA::new("TCAATGCATGCGGGTCTATATGCAT");
+        assert_eq!(
+            dna.reverse_palindromes(4, 12),
+            vec![
+                (3, 6),
+                (4, 4),
+                (5, 6),
+                (6, 4),
+                (16, 4),
+                (17, 4),
+                (19, 6),
+                (20, 4)
+            ]
+        );
+        assert!(dna
+            .reverse_palindromes(1, 25)
+            .iter()
+            .all(|&(_, length)| length % 2 == 0));
+        assert!(DNA::new("").reverse_palindromes(4, 12).is_empty());
+    }
+
+    #[test]
+    fn it_removes_introns_in_order() {
+        let dna = DNA::new("AAGGTTCCGGT");
+        assert_eq!(dna.splice(&["GGT", "GTTC"]).content(), "AATCCGGT");
+        assert_eq!(dna.splice(&["GTTC", "GGT"]).content(), "AAGC");
+        assert_eq!(dna.splice(&["CAT"]).content(), "AAGGTTCCGGT");
+    }
+
+    #[test]
+    fn it_handles_strands_without_transversions() {
+        let dna = DNA::new("ACGT");
+        assert_eq!(transition_transversion_ratio(&dna, &dna), Ok(0f64));
+        assert_eq!(
+            transition_transversion_ratio(&dna, &DNA::new("GCGT")),
+            Ok(f64::INFINITY)
+        );
+        assert_eq!(
+            transition_transversion_ratio(&dna, &DNA::new("ACG")),
+            Err(GenError::MismatchedLength { left: 4, right: 3 })
+        );
+    }
+
+    #[test]
+    fn it_streams_fasta_records() {
+        let input = ">Rosalind_1\nACGT\nTTAA\n\n>Rosalind_2\nGGCC";
+        let mut reader = FastaReader::new(io::Cursor::new(input));
+        assert_eq!(reader.next().unwrap().unwrap().content(), "ACGTTTAA");
+        let last = reader.next().unwrap().unwrap();
+        assert_eq!(last.label(), "Rosalind_2");
+        assert_eq!(last.content(), "GGCC");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn it_reads_a_single_fasta_record() {
+        let input = ">Rosalind_1\nACGT\nTTAA\n>Rosalind_2\nGGCC\n";
+        let fasta = read_single_fasta(io::Cursor::new(input)).unwrap();
+        assert_eq!(fasta.label(), "Rosalind_1");
+        assert_eq!(fasta.content(), "ACGTTTAA");
+
+        assert_eq!(
+            read_single_fasta(io::Cursor::new("")).err(),
+            Some(GenError::EmptyStrand)
+        );
+        assert_eq!(
+            read_single_fasta(io::Cursor::new("ACGT\n")).err(),
+            Some(GenError::EmptyLabel)
+        );
+    }
+
+    #[test]
+    fn it_builds_a_length_histogram() {
+        let records = ["ACGT", "ACGTACGTAC", "ACGTACG", "ACGTACGTA", "", "ACG"]
+            .iter()
+            .enumerate()
+            .map(|(index, dna)| FASTA::new(DNA::new(dna), &format!("DNA_{}", index)))
+            .collect::<Vec<_>>();
+
+        let histogram = length_histogram(&records, 5);
+        assert_eq!(
+            histogram.into_iter().collect::<Vec<_>>(),
+            vec![(0, 3), (5, 2), (10, 1)]
+        );
+
+        let histogram = length_histogram(&records, 0);
+        assert_eq!(histogram.len(), 6);
+        assert_eq!(
+            histogram.keys().collect::<Vec<_>>(),
+            vec![&0, &3, &4, &7, &9, &10]
+        );
+
+        assert!(length_histogram(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn it_computes_assembly_stats() {
+        // Total length 38: the two longest contigs cover 18, and the third brings it to 24
+        let records = [2, 3, 4, 5, 6, 8, 10]
+            .iter()
+            .map(|&length| FASTA::new(DNA::new(&"A".repeat(length)), "contig"))
+            .collect::<Vec<_>>();
+
+        let stats = assembly_stats(&records);
+        assert_eq!(stats.total_length, 38);
+        assert_eq!(stats.count, 7);
+        assert!((stats.mean_length - 38f64 / 7f64).abs() < 1e-12);
+        assert_eq!(stats.median_length, 5f64);
+        assert_eq!(stats.n50, 6);
+
+        let stats = assembly_stats(&records[..4]);
+        assert_eq!(stats.median_length, 3.5);
+        assert_eq!(stats.n50, 4);
+
+        let empty = assembly_stats(&[]);
+        assert_eq!((empty.count, empty.total_length, empty.n50), (0, 0, 0));
+        assert_eq!(empty.mean_length, 0f64);
+    }
+
+    #[test]
+    fn it_computes_gc_content_of_empty_strands() {
+        let dna = DNA::new("");
+        assert_eq!(dna.gc_content(), 0f64);
+        assert_eq!(dna.gc_ratio(), 0f64);
+        assert_eq!(dna.try_gc_content(), Err(GenError::EmptyStrand));
+    }
+
+    #[test]
+    fn it_finds_the_first_record_with_max_gc_content() {
+        let records = [
+            FASTA::new(DNA::new("GCAT"), "DNA_1"),
+            FASTA::new(DNA::new("GCGT"), "DNA_2"),
+            FASTA::new(DNA::new("CGTG"), "DNA_3"),
+        ];
+        let (record, gc) = max_gc_content(&records).unwrap();
+        assert_eq!(record.label(), "DNA_2");
+        assert_eq!(gc, 75f64);
+        assert!(max_gc_content(&[]).is_none());
+    }
+
+    #[test]
+    fn it_finds_overlapping_glycosylation_motifs() {
+        let motif = ProteinMotif::try_new("N{P}[ST]{P}").unwrap();
+        assert_eq!(Protein::new("NNSSNNTP").find_motif(&motif), vec![1, 2]);
+        assert_eq!(Protein::new("NPSA").find_motif(&motif), Vec::<usize>::new());
+        assert_eq!(Protein::new("NA").find_motif(&motif), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn it_finds_motifs_with_symbol_sets() {
+        let motif = compile_motif("G[AG]{T}C");
+        assert_eq!(motif.find_all("GGACGAGCTGACC"), vec![0, 4, 9]);
+        assert_eq!(motif.find_all("GAT"), Vec::<usize>::new());
+
+        let glycosylation = compile_motif("N{P}[ST]{P}");
+        assert_eq!(glycosylation.find_all("NNSSNNTP"), vec![0, 1]);
+        assert_eq!(
+            Protein::new("NNSSNNTP").find_motif(&glycosylation),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid motif 'A[CG'")]
+    fn it_panics_on_compiling_an_invalid_motif() {
+        compile_motif("A[CG");
+    }
+
+    #[test]
+    fn it_rejects_invalid_motifs() {
+        for pattern in &["", "N[]", "N{P", "N[ST}", "n"] {
+            assert_eq!(
+                ProteinMotif::try_new(pattern).err(),
+                Some(GenError::InvalidMotif(pattern.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn it_computes_a_symmetric_p_distance_matrix() {
+        let strands = [
+            DNA::new("TTTCCATTTA"),
+            DNA::new("GATTCATTTC"),
+            DNA::new("TTTCCATTTT"),
+            DNA::new("GTTCCATTTA"),
+        ];
+        let matrix = p_distance_matrix(&strands).unwrap();
+        let expected = [
+            [0.0, 0.4, 0.1, 0.1],
+            [0.4, 0.0, 0.4, 0.3],
+            [0.1, 0.4, 0.0, 0.2],
+            [0.1, 0.3, 0.2, 0.0],
+        ];
+
+        for i in 0..strands.len() {
+            for j in 0..strands.len() {
+                assert!((matrix[i][j] - expected[i][j]).abs() < 1e-9);
+                assert_eq!(matrix[i][j], matrix[j][i]);
+            }
+        }
+    }
+
+    #[test]
+    fn it_rejects_p_distances_of_unequal_strands() {
+        assert_eq!(
+            p_distance_matrix(&[DNA::new("ACGT"), DNA::new("ACG")]),
+            Err(GenError::MismatchedLength { left: 4, right: 3 })
+        );
+        assert_eq!(
+            p_distance_matrix(&[DNA::new(""), DNA::new("")]),
+            Err(GenError::EmptyStrand)
+        );
+    }
+
+    #[test]
+    fn it_computes_edit_distances() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "ACGT"), 4);
+        assert_eq!(edit_distance("ACGT", ""), 4);
+        assert_eq!(edit_distance("ACGT", "ACGT"), 0);
+        assert_eq!(edit_distance("ACGT", "AGT"), 1);
+        assert_eq!(edit_distance("KITTEN", "SITTING"), 3);
+    }
+
+    #[test]
+    fn it_validates_protein_strings() {
+        assert!(Protein::new("MA*").is_valid());
+        assert!(!Protein::new("MBA").is_valid());
+        assert_eq!(
+            Protein::try_new("MBA").err(),
+            Some(GenError::InvalidAminoAcid('B'))
+        );
+        assert_eq!(Protein::try_new("").err(), Some(GenError::EmptyStrand));
+
+        let protein = Protein::try_new("MA*").unwrap();
+        assert_eq!(protein.rna_count(1_000_000).remainder(), 12);
+    }
+
+    #[test]
+    fn it_skips_a_trailing_partial_codon() {
+        let rna = RNA::new("AUGGCCU");
+        assert_eq!(rna.codons().collect::<Vec<_>>(), vec!["AUG", "GCC"]);
+        assert_eq!(RNA::new("AU").codons().count(), 0);
+    }
+
+    #[test]
+    fn it_translates_single_codons() {
+        assert_eq!(translate_codon("AUG"), Ok(Some('M')));
+        assert_eq!(translate_codon("UGA"), Ok(None));
+        assert_eq!(
+            translate_codon("ATG"),
+            Err(GenError::InvalidCodon("ATG".to_string()))
+        );
+        assert_eq!(
+            translate_codon("AU"),
+            Err(GenError::InvalidCodon("AU".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_lists_codons_for_amino_acids() {
+        assert_eq!(codons_for_amino_acid('L').unwrap().len(), 6);
+        assert_eq!(
+            codons_for_amino_acid(' ').err(),
+            Some(GenError::InvalidAminoAcid(' '))
+        );
+    }
+
+    #[test]
+    fn it_borrows_genetic_strings_as_str() {
+        fn symbols<T: AsRef<str>>(strand: T) -> usize {
+            strand.as_ref().chars().count()
+        }
+
+        assert_eq!(symbols(DNA::new("ACGT")), 4);
+        assert_eq!(symbols(RNA::new("ACGU")), 4);
+        assert_eq!(symbols(Protein::new("MA")), 2);
+        assert_eq!(&*RNA::new("ACGU"), "ACGU");
+        assert!(Protein::new("MA").ends_with('A'));
+    }
+
+    #[test]
+    fn it_counts_perfect_matchings() {
+        assert_eq!(RNA::new("").perfect_matchings(), Ok(1));
+        assert_eq!(RNA::new("AU").perfect_matchings(), Ok(1));
+        assert_eq!(
+            RNA::new("ACGG").perfect_matchings(),
+            Err(GenError::UnpairedSymbols('A', 'U'))
+        );
+        assert_eq!(
+            RNA::new("AUCGG").perfect_matchings(),
+            Err(GenError::UnpairedSymbols('C', 'G'))
+        );
+        assert_eq!(
+            RNA::new("AT").perfect_matchings(),
+            Err(GenError::InvalidRnaSymbol {
+                symbol: 'T',
+                index: 1
+            })
+        );
+
+        let long = RNA::new(&"AU".repeat(40));
+        assert_eq!(long.perfect_matchings(), Err(GenError::Overflow));
+    }
+
+    #[test]
+    fn it_assembles_reads_only_when_unique() {
+        assert_eq!(
+            assemble_reads(&[DNA::new("ACGTAC"), DNA::new("GTACCA")]),
+            Some(DNA::new("ACGTACCA"))
+        );
+        assert_eq!(assemble_reads(&[DNA::new("ACGT")]), Some(DNA::new("ACGT")));
+        assert_eq!(assemble_reads(&[]), None);
+
+        // No overlap of more than half the read length
+        assert_eq!(
+            assemble_reads(&[DNA::new("ACGTAC"), DNA::new("ACCA")]),
+            None
+        );
+
+        // Two reads could follow the first one
+        assert_eq!(
+            assemble_reads(&[DNA::new("ACGTAC"), DNA::new("GTACCA"), DNA::new("GTACTT")]),
+            None
+        );
+    }
+
+    #[test]
+    fn it_finds_restriction_sites_from_one() {
+        let dna = DNA::new("TCAATGCATGCGGGTCTATATGCAT");
+        let record = FASTA::new(dna.clone(), "Rosalind_24");
+        let sites = restriction_sites(&record, 4, 12);
+        let palindromes = dna.reverse_palindromes(4, 12);
+
+        assert_eq!(sites.len(), 8);
+        for (site, palindrome) in sites.iter().zip(palindromes.iter()) {
+            assert_eq!(*site, (palindrome.0 + 1, palindrome.1));
+        }
+        assert_eq!(sites.last(), Some(&(21, 4)));
+    }
+
+    #[test]
+    fn it_round_trips_sample_rna() {
+        for protein in &["", "M", "MAMAPRRSTS", "ACDEFGHIKLMNPQRSTVWY"] {
+            let protein = Protein::new(protein);
+            assert_eq!(Protein::from(protein.sample_rna().unwrap()), protein);
+        }
+
+        assert_eq!(
+            Protein::new("MA*").sample_rna(),
+            Protein::new("MA").sample_rna()
+        );
+        assert_eq!(
+            Protein::new("MXA").sample_rna(),
+            Err(GenError::InvalidAminoAcid('X'))
+        );
+    }
+
+    #[test]
+    fn it_sorts_records_by_gc_with_empty_records_last() {
+        let mut records = [
+            FASTA::new(DNA::new(""), "DNA_1"),
+            FASTA::new(DNA::new("ATAT"), "DNA_2"),
+            FASTA::new(DNA::new("GCGC"), "DNA_3"),
+            FASTA::new(DNA::new("ATGC"), "DNA_4"),
+            FASTA::new(DNA::new("TAAT"), "DNA_5"),
+        ];
+
+        let ranked = ranked_by_gc(&records)
+            .into_iter()
+            .map(|(record, gc)| (record.label(), gc))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                ("DNA_3".to_string(), 100f64),
+                ("DNA_4".to_string(), 50f64),
+                ("DNA_2".to_string(), 0f64),
+                ("DNA_5".to_string(), 0f64),
+                ("DNA_1".to_string(), 0f64),
+            ]
+        );
+
+        sort_by_gc(&mut records);
+        let labels = records
+            .iter()
+            .map(|record| record.label())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, vec!["DNA_3", "DNA_4", "DNA_2", "DNA_5", "DNA_1"]);
+    }
+
+    #[test]
+    fn it_counts_amino_acids_as_is() {
+        let counts = Protein::new("MKBK").amino_acid_counts();
+        assert_eq!(counts.get(&'K'), Some(&2));
+        assert_eq!(counts.get(&'B'), Some(&1));
+        assert_eq!(counts.keys().collect::<String>(), "BKM");
+        assert!(Protein::new("").amino_acid_counts().is_empty());
+    }
+
+    #[test]
+    fn it_counts_symbol_frequencies() {
+        let counts = DNA::new("AACGTT").symbol_frequencies();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![('A', 2), ('C', 1), ('G', 1), ('T', 2)]
+        );
+
+        let counts = RNA::new("UUUA").symbol_frequencies();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![('A', 1), ('U', 3)]
+        );
+        assert!(RNA::new("").symbol_frequencies().is_empty());
+    }
+
+    #[test]
+    fn it_transcribes_preserving_case() {
+        let rna = RNA::from(DNA::new("AcGt"));
+        assert_eq!(rna.content(), "AcGu");
+        assert_eq!(DNA::from(rna).content(), "AcGt");
+        assert_eq!(RNA::from(DNA::new("tttt")).content(), "uuuu");
+    }
+
+    #[test]
+    fn it_finds_the_nearest_strand_of_equal_length() {
+        let query = DNA::new("ACGTAC");
+        let candidates = [
+            DNA::new("ACGTA"),
+            DNA::new("TCGTAA"),
+            DNA::new("ACGTAA"),
+            DNA::new("ACGTAG"),
+        ];
+
+        assert_eq!(
+            nearest_by_hamming(&query, &candidates),
+            Some((&candidates[2], 1))
+        );
+        assert_eq!(nearest_by_hamming(&query, &candidates[..1]), None);
+        assert_eq!(nearest_by_hamming(&query, &[]), None);
+    }
+
+    #[test]
+    fn it_computes_gc_content_windows() {
+        let dna = DNA::new("GGCCGCATTATA");
+        assert_eq!(dna.gc_content_windows(6, 3), vec![100f64, 50f64, 0f64]);
+        // The last partial window ("TA") is excluded
+        assert_eq!(dna.gc_content_windows(5, 5).len(), 2);
+        assert!(dna.gc_content_windows(13, 1).is_empty());
+        assert!(dna.gc_content_windows(0, 1).is_empty());
+        assert!(dna.gc_content_windows(4, 0).is_empty());
+    }
+
+    #[test]
+    fn it_indexes_dna_by_symbol() {
+        let dna = DNA::new("GATTACA");
+        let positions = crate::substring_locations(dna.content(), "TA");
+        assert_eq!(dna.base_at(positions[0]), Some('T'));
+        assert_eq!(&dna[positions[0] + 1], "A");
+        assert_eq!(dna.base_at(7), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 7 is out of range for a DNA strand of length 7")]
+    fn it_panics_on_indexing_past_the_end() {
+        let _ = &DNA::new("GATTACA")[7];
+    }
+
+    #[test]
+    fn it_complements_iupac_ambiguity_codes() {
+        for &(symbol, complement) in &[('R', 'Y'), ('K', 'M'), ('B', 'V'), ('D', 'H')] {
+            assert_eq!(DNA::complement_iupac(symbol), complement);
+            assert_eq!(DNA::complement_iupac(complement), symbol);
+        }
+        for &symbol in &['N', 'S', 'W'] {
+            assert_eq!(DNA::complement_iupac(symbol), symbol);
+        }
+
+        let dna = DNA::new("ACGTNRYSWKMBDHV");
+        assert_eq!(dna.reverse_complement_iupac(), DNA::new("BDHVKMWSRYNACGT"));
+        assert_eq!(
+            dna.reverse_complement_iupac().reverse_complement_iupac(),
+            dna
+        );
+        assert_eq!(
+            DNA::new("AACGGT").reverse_complement_iupac(),
+            DNA::new("AACGGT").reverse_complement()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid DNA symbol 'N' at position 3")]
+    fn it_rejects_ambiguity_codes_in_strict_reverse_complement() {
+        DNA::new("ACGN").reverse_complement();
+    }
+
+    #[test]
+    fn it_converts_amino_acids_to_three_letter_codes() {
+        let protein = Protein::new("MA");
+        assert_eq!(
+            protein.three_letter_codes(),
+            Ok(vec![String::from("Met"), String::from("Ala")])
+        );
+
+        // Every standard amino acid has a distinct code
+        let all = AMINO_ACID_SYMBOLS.iter().collect::<String>();
+        let codes = Protein::new(&all).three_letter_codes().unwrap();
+        assert_eq!(codes.iter().collect::<HashSet<_>>().len(), 20);
+
+        assert_eq!(
+            Protein::new("MA*").three_letter_codes().unwrap().last(),
+            Some(&String::from("Ter"))
+        );
+        assert_eq!(
+            Protein::new("ma").three_letter_codes(),
+            Err(GenError::InvalidAminoAcid('m'))
+        );
+    }
+
+    #[test]
+    fn it_detects_reverse_palindromes() {
+        assert!(DNA::new("GCATGC").is_reverse_palindrome());
+        assert!(!DNA::new("GCATGG").is_reverse_palindrome());
+        // No symbol is its own complement, so odd lengths never match
+        assert!(!DNA::new("GCAGC").is_reverse_palindrome());
+        assert!(!DNA::new("GCNGC").is_reverse_palindrome());
+        assert!(DNA::new("").is_reverse_palindrome());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_generates_random_strands() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let dna = DNA::random(1000, &mut rng);
+        assert_eq!(dna.length(), 1000);
+        assert!(DNA::try_new(dna.content()).is_ok());
+        // Every symbol is all but certain to appear in a strand this long
+        assert_eq!(dna.symbol_frequencies().len(), 4);
+
+        let rna = RNA::random(1000, &mut rng);
+        assert_eq!(rna.length(), 1000);
+        assert!(RNA::try_new(rna.content()).is_ok());
+
+        assert_eq!(DNA::random(0, &mut rng), DNA::new(""));
+    }
+
+    #[test]
+    fn it_splits_dna_into_reading_frames() {
+        let dna = DNA::new("ATGGCCTAACCATTAG");
+        let frames = dna.reading_frames();
+        assert_eq!(frames[0], RNA::from(dna.clone()));
+        assert_eq!(frames[2].content(), "GGCCUAACCAUUAG");
+        assert_eq!(frames[3], RNA::from(dna.reverse_complement()));
+        for offset in 0..3 {
+            assert_ne!(frames[offset], frames[offset + 3]);
+        }
+
+        let short = DNA::new("A").reading_frames();
+        assert_eq!(short[0], RNA::new("A"));
+        assert_eq!(short[2], RNA::new(""));
+        assert_eq!(short[3], RNA::new("U"));
+    }
+
+    #[test]
+    fn it_normalizes_trailing_stops() {
+        assert_ne!(Protein::new("MAST*"), Protein::new("MAST"));
+        assert_eq!(
+            Protein::new("MAST*").normalized(),
+            Protein::new("MAST").normalized()
+        );
+        assert_eq!(Protein::new("MAST* *\n").normalized(), Protein::new("MAST"));
+        // Only trailing stops are removed
+        assert_eq!(Protein::new("MA*ST").normalized(), Protein::new("MA*ST"));
+        assert_eq!(Protein::new("*").normalized(), Protein::new(""));
+    }
+
+    #[test]
+    fn it_finds_the_minimum_gc_skew() {
+        // The skew falls over the C-rich first half and rises over the G-rich second half
+        let dna = DNA::new("ACCTCAGTGGAG");
+        assert_eq!(
+            dna.gc_skew(),
+            vec![0, -1, -2, -2, -3, -3, -2, -2, -1, 0, 0, 1]
+        );
+        assert_eq!(dna.min_skew_positions(), vec![4, 5]);
+
+        let dna = DNA::new("CCCCGGGG");
+        assert_eq!(dna.min_skew_positions(), vec![3]);
+        assert_eq!(dna.gc_skew().last(), Some(&0));
+
+        assert_eq!(DNA::new("").gc_skew(), Vec::<i64>::new());
+        assert_eq!(DNA::new("").min_skew_positions(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn it_corrects_reads() {
+        let reads = [
+            "TCATC", "TTCAT", "TCATC", "TGAAA", "GAGGA", "TTTCA", "ATCAA", "TTGAT", "TTTCC",
+        ]
+        .iter()
+        .map(|read| DNA::new(read))
+        .collect::<Vec<_>>();
+
+        let expected = [("TTCAT", "TTGAT"), ("GAGGA", "GATGA"), ("TTTCC", "TTTCA")]
+            .iter()
+            .map(|&(wrong, corrected)| (DNA::new(wrong), DNA::new(corrected)))
+            .collect::<Vec<_>>();
+        assert_eq!(correct_reads(&reads), expected);
+    }
+
+    #[test]
+    fn it_skips_reads_with_ambiguous_corrections() {
+        // "AAAA" is one symbol away from both correct reads
+        let reads = ["AAAC", "AAAC", "AAAG", "AAAG", "AAAA"]
+            .iter()
+            .map(|read| DNA::new(read))
+            .collect::<Vec<_>>();
+        assert_eq!(correct_reads(&reads), vec![]);
+    }
+
+    #[test]
+    fn it_converts_strands_to_uppercase() {
+        let dna = DNA::new("acgTn").to_uppercase();
+        assert_eq!(dna, DNA::new("ACGTN"));
+        assert_eq!(
+            DNA::try_new(dna.content()),
+            Err(GenError::InvalidDnaSymbol {
+                symbol: 'N',
+                index: 4
+            })
+        );
+        // The ambiguity code can still be complemented
+        assert_eq!(dna.reverse_complement_iupac(), DNA::new("NACGT"));
+
+        let rna = RNA::new("acgu").to_uppercase();
+        assert_eq!(RNA::try_new(rna.content()), Ok(RNA::new("ACGU")));
+    }
+
+    #[test]
+    fn it_counts_codon_usage() {
+        // The invalid codon and the partial codon at the end are skipped
+        let rna = RNA::new("CUGUUAAGCUAGCUUUCUXAGUA");
+        let usage = rna.codon_usage();
+        assert_eq!(usage.get(&'L'), Some(&3));
+        assert_eq!(usage.get(&'S'), Some(&2));
+        assert_eq!(usage.get(&STOP_SYMBOL), Some(&1));
+        assert_eq!(usage.values().sum::<usize>(), 6);
+
+        assert!(RNA::new("").codon_usage().is_empty());
+    }
+
+    #[test]
+    fn it_transcribes_without_consuming_the_strand() {
+        let dna = DNA::new("GATGGAACTTGACTACGTAAATT");
+        let rna = dna.transcribe();
+        assert_eq!(rna, RNA::new("GAUGGAACUUGACUACGUAAAUU"));
+        // The DNA strand is still available
+        assert_eq!(dna.length(), rna.length());
+        assert_eq!(rna.reverse_transcribe(), dna);
+        assert_eq!(RNA::from(dna.clone()), rna);
+        assert_eq!(DNA::from(rna), dna);
+    }
+
+    #[test]
+    fn it_validates_strings_without_building_strands() {
+        assert!(is_valid_dna(""));
+        assert!(is_valid_dna(&"ACGT".repeat(1000)));
+        assert!(!is_valid_dna("ACGTN"));
+        assert!(!is_valid_dna("acgt"));
+        assert!(!is_valid_dna("ACGÅ"));
+
+        assert!(is_valid_rna("ACGU"));
+        assert!(!is_valid_rna("ACGT"));
+
+        assert!(is_valid_protein("ACDEFGHIKLMNPQRSTVWY*"));
+        assert!(!is_valid_protein("MTBS"));
+        assert!(!is_valid_protein("MTÅS"));
+    }
+
+    #[test]
+    fn it_reports_every_invalid_position() {
+        let dna = DNA::new("NACGTuAXCG-");
+        assert_eq!(
+            dna.invalid_positions(),
+            vec![(0, 'N'), (5, 'u'), (7, 'X'), (10, '-')]
+        );
+        assert!(DNA::new("ACGT").invalid_positions().is_empty());
+
+        assert_eq!(
+            RNA::new("ACGTUAT").invalid_positions(),
+            vec![(3, 'T'), (6, 'T')]
+        );
+        assert_eq!(
+            Protein::new("MJTBSOM*").invalid_positions(),
+            vec![(1, 'J'), (3, 'B'), (5, 'O')]
+        );
+    }
+
+    #[test]
+    fn it_records_the_codon_index_of_the_stop() {
+        let rna = RNA::new("AUGGCCAXGUGGUGAAAAUAG");
+        let (protein, stop) = rna.translate();
+        assert_eq!(protein, Protein::new("MAW"));
+        assert_eq!(stop, Some(4));
+        assert_eq!(protein, Protein::from(rna));
+
+        assert_eq!(RNA::new("").translate(), (Protein::new(""), None));
+        assert_eq!(RNA::new("UAGAUG").translate(), (Protein::new(""), Some(0)));
+    }
+
+    #[test]
+    fn it_translates_under_alternative_genetic_codes() {
+        let rna = RNA::new("AUGUGGUGAAUAAGA");
+        assert_eq!(Protein::from(rna.clone()), Protein::new("MW"));
+        assert_eq!(
+            Protein::from_rna_with_code(&rna, GeneticCode::default()),
+            Protein::new("MW")
+        );
+        assert_eq!(
+            Protein::from_rna_with_code(&rna, GeneticCode::VertebrateMitochondrial),
+            Protein::new("MWWM")
+        );
+        assert_eq!(
+            Protein::from_rna_with_code(&rna, GeneticCode::InvertebrateMitochondrial),
+            Protein::new("MWWMS")
+        );
+        assert_eq!(
+            Protein::from_rna_with_code(&RNA::new("CUGUGA"), GeneticCode::YeastMitochondrial),
+            Protein::new("TW")
+        );
+    }
+
+    #[test]
+    fn it_reverse_complements_rna() {
+        let rna = RNA::new("AUGCCGUA");
+        assert_eq!(rna.reverse_complement(), RNA::new("UACGGCAU"));
+        assert_eq!(rna.reverse_complement().reverse_complement(), rna);
+        assert_eq!(RNA::new("").reverse_complement(), RNA::new(""));
+    }
+
+    #[test]
+    fn it_extracts_subsequences_by_character_position() {
+        let dna = DNA::new("GATATATGCATATACTT");
+        let motif_regions = crate::substring_locations(dna.content(), "ATAT")
+            .into_iter()
+            .map(|start| dna.subseq(start.saturating_sub(1), 6))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            motif_regions,
+            vec![
+                Some(DNA::new("GATATA")),
+                Some(DNA::new("TATATG")),
+                Some(DNA::new("CATATA")),
+            ]
+        );
+
+        assert_eq!(dna.subseq(17, 0), Some(DNA::new("")));
+        assert_eq!(dna.subseq(15, 3), None);
+        assert_eq!(dna.subseq(18, 0), None);
+        assert_eq!(dna.subseq(1, usize::MAX), None);
+    }
+
+    #[test]
+    fn it_complements_dna_without_reversing() {
+        let dna = DNA::new("AACGGT");
+        let complement = dna.complement_strand();
+        assert_eq!(complement, DNA::new("TTGCCA"));
+
+        let reversed = complement.content().chars().rev().collect::<String>();
+        assert_eq!(DNA::new(&reversed), dna.reverse_complement());
+    }
+
+    #[test]
+    fn it_reverse_complements_through_the_complement_trait() {
+        fn round_trip<T: Complement + PartialEq + fmt::Debug>(strand: &T, expected: &T) {
+            assert_eq!(&Complement::reverse_complement(strand), expected);
+            assert_eq!(&Complement::reverse_complement(expected), strand);
+        }
+
+        round_trip(&DNA::new("AACGGT"), &DNA::new("ACCGTT"));
+        round_trip(&RNA::new("AUGCCGUA"), &RNA::new("UACGGCAU"));
+        round_trip(&DNA::new(""), &DNA::new(""));
+
+        // Both strand types report the position of an invalid symbol through the trait
+        assert_eq!(
+            Complement::try_reverse_complement(&DNA::new("ACXT")),
+            Err(GenError::InvalidDnaSymbol {
+                symbol: 'X',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Complement::try_reverse_complement(&RNA::new("AXGU")),
+            Err(GenError::InvalidRnaSymbol {
+                symbol: 'X',
+                index: 1
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid RNA symbol 'X' at position 1")]
+    fn it_panics_on_reverse_complementing_invalid_rna() {
+        RNA::new("AXGU").reverse_complement();
+    }
+
+    #[test]
+    fn it_counts_rna_strands_under_a_large_modulus() {
+        let protein = Protein::new(&"L".repeat(20));
+        assert_eq!(protein.rna_count(i32::MAX as u32).remainder(), 729_673_610);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid amino acid 'B'")]
+    fn it_panics_on_invalid_amino_acids() {
+        Protein::new("MB").rna_count(1_000_000);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serializes_genetic_strings() {
+        let dna = DNA::new("ACGT");
+        let json = serde_json::to_string(&dna).unwrap();
+        assert_eq!(json, "\"ACGT\"");
+        assert_eq!(serde_json::from_str::<DNA>(&json).unwrap(), dna);
+
+        let fasta = FASTA::new(DNA::new("ACGT"), "DNA_1");
+        let json = serde_json::to_string(&fasta).unwrap();
+        assert_eq!(json, r#"{"label":"DNA_1","content":"ACGT"}"#);
+        let fasta = serde_json::from_str::<FASTA>(&json).unwrap();
+        assert_eq!(fasta.label(), "DNA_1");
+        assert_eq!(fasta.content(), "ACGT");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_rejects_invalid_deserialized_genetic_strings() {
+        let err = serde_json::from_str::<DNA>(r#""ACGX""#).err().unwrap();
+        let expected = GenError::InvalidDnaSymbol {
+            symbol: 'X',
+            index: 3,
+        };
+        assert_eq!(err.to_string(), expected.to_string());
+        assert!(serde_json::from_str::<RNA>(r#""ACGT""#).is_err());
+        assert!(serde_json::from_str::<Protein>(r#""MTBS""#).is_err());
+        assert!(serde_json::from_str::<FASTA>(r#"{"label":"DNA_1","content":"ACGX"}"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_serializes_modular_values() {
+        #[derive(Serialize, Deserialize)]
+        struct Count {
+            #[serde(with = "modulo_serde")]
+            value: Modulo,
+        }
+
+        let count = Count {
+            value: modulo!(12, 5),
+        };
+        let json = serde_json::to_string(&count).unwrap();
+        assert_eq!(json, r#"{"value":{"remainder":2,"modulus":5}}"#);
+        let count = serde_json::from_str::<Count>(&json).unwrap();
+        assert_eq!(count.value.remainder(), 2);
+        assert_eq!(count.value.modulus(), 5);
+
+        for json in &[
+            r#"{"value":{"remainder":2,"modulus":0}}"#,
+            r#"{"value":{"remainder":2,"modulus":2147483648}}"#,
+        ] {
+            let err = serde_json::from_str::<Count>(json).err().unwrap();
+            assert!(err.to_string().starts_with("invalid modulus"));
+        }
     }
 }
@@ -1,3 +1,9 @@
+use crate::gen_str::GenError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
 use std::fmt;
 use std::ops::Mul;
 
@@ -11,6 +17,7 @@ use std::ops::Mul;
 /// dominant, the organism is considered Homozygous Dominant for the factor. If they are both
 /// recessive, the organism is Homozygous Recessive. Otherwise, it is Heterozygous.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Allelle {
     /// Dominant allelle
     D,
@@ -32,6 +39,7 @@ impl fmt::Display for Allelle {
 /// Specifically, this represents a particular genetic factor. However, in context, a factor is
 /// owned by an organism which is easier to conceptualize.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Organism(Allelle, Allelle);
 
 impl Organism {
@@ -126,6 +134,52 @@ impl Organism {
             .fold(0u32, |acc, ch| if ch.is_dominant() { acc + 1 } else { acc });
         f64::from(dominant) / children.len() as f64
     }
+
+    /// Return the probability of each genotype of a child of this organism and another
+    ///
+    /// Each distinct genotype among the offspring listed by `Organism * Organism` is mapped to the
+    /// probability of a child having it, so the probabilities sum to 1. The genotypes are ordered
+    /// from homozygous dominant to homozygous recessive.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosalind::gene::*;
+    ///
+    /// let parent = Organism::heterozygous();
+    /// let distribution = parent.offspring_distribution(parent);
+    ///
+    /// assert_eq!(
+    ///     distribution.into_iter().collect::<Vec<_>>(),
+    ///     vec![
+    ///         (Organism::homozygous_dominant(), 0.25),
+    ///         (Organism::heterozygous(), 0.5),
+    ///         (Organism::homozygous_recessive(), 0.25),
+    ///     ]
+    /// );
+    ///
+    /// let distribution = parent.offspring_distribution(Organism::homozygous_recessive());
+    /// assert_eq!(distribution.len(), 2);
+    /// assert_eq!(distribution[&Organism::heterozygous()], 0.5);
+    /// ```
+    pub fn offspring_distribution(self, other: Organism) -> BTreeMap<Organism, f64> {
+        let children = self * other;
+        let probability = 1f64 / children.len() as f64;
+
+        children
+            .iter()
+            .fold(BTreeMap::new(), |mut distribution, &child| {
+                *distribution.entry(child).or_insert(0f64) += probability;
+                distribution
+            })
+    }
+
+    // Return the number of recessive allelles of the organism
+    fn recessive_allelles(self) -> usize {
+        [self.0, self.1]
+            .iter()
+            .filter(|&&allelle| allelle == Allelle::R)
+            .count()
+    }
 }
 
 impl Mul for Organism {
@@ -162,6 +216,47 @@ impl Mul for Organism {
     }
 }
 
+impl TryFrom<&str> for Organism {
+    type Error = GenError;
+
+    /// Parse an organism from its genotype
+    ///
+    /// The genotype is written as two allelles, `D` or `R`, in either order. Returns
+    /// `GenError::InvalidGenotype` for any other string.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosalind::gen_str::GenError;
+    /// use rosalind::gene::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// assert_eq!(Organism::try_from("DD"), Ok(Organism::homozygous_dominant()));
+    /// assert_eq!(Organism::try_from("DR"), Ok(Organism::heterozygous()));
+    /// assert_eq!(Organism::try_from("RD"), Ok(Organism::heterozygous()));
+    /// assert_eq!(Organism::try_from("RR"), Ok(Organism::homozygous_recessive()));
+    /// assert_eq!(
+    ///     Organism::try_from("DX"),
+    ///     Err(GenError::InvalidGenotype("DX".to_string()))
+    /// );
+    /// ```
+    fn try_from(genotype: &str) -> Result<Self, Self::Error> {
+        let allelles = genotype
+            .trim()
+            .chars()
+            .map(|ch| match ch {
+                'D' => Some(Allelle::D),
+                'R' => Some(Allelle::R),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+
+        match allelles.as_ref().map(|allelles| &allelles[..]) {
+            Some(&[a, b]) => Ok(Organism(a, b)),
+            _ => Err(GenError::InvalidGenotype(genotype.to_string())),
+        }
+    }
+}
+
 impl fmt::Display for Organism {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}{}", self.0, self.1)
@@ -191,13 +286,64 @@ impl PartialEq for Organism {
     }
 }
 
+impl Eq for Organism {}
+
+impl Ord for Organism {
+    /// Orders organisms by their number of recessive allelles
+    ///
+    /// Homozygous dominant organisms come first and homozygous recessive organisms last. As with
+    /// equality, the order of the allelles in the organism doesn't matter.
+    ///
+    /// # Example
+    /// ```rust
+    /// use rosalind::gene::*;
+    ///
+    /// assert!(Organism::homozygous_dominant() < Organism::heterozygous());
+    /// assert!(Organism::heterozygous() < Organism::homozygous_recessive());
+    /// ```
+    fn cmp(&self, other: &Organism) -> Ordering {
+        self.recessive_allelles().cmp(&other.recessive_allelles())
+    }
+}
+
+impl PartialOrd for Organism {
+    fn partial_cmp(&self, other: &Organism) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Represents the makeup of a population
 ///
 /// A population will consist of a number of homozygous dominant, homozygous recessive and
 /// heterozygous organisms.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Population(u32, u32, u32);
 
+/// Builds a population from named counts of each type of organism
+///
+/// Counts that are not set are 0. This is created by `Population::builder`.
+///
+/// # Example
+/// ```rust
+/// use rosalind::gene::*;
+///
+/// let pop = Population::builder()
+///     .homozygous_dominant(3)
+///     .heterozygous(4)
+///     .homozygous_recessive(5)
+///     .build();
+/// assert_eq!(pop, Population::new(3, 4, 5));
+///
+/// let pop = Population::builder().heterozygous(2).build();
+/// assert_eq!(pop, Population::new(0, 2, 0));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PopulationBuilder {
+    dd: u32,
+    dr: u32,
+    rr: u32,
+}
+
 impl Population {
     /// Creates a new population
     ///
@@ -211,6 +357,13 @@ impl Population {
         Population(dd, dr, rr)
     }
 
+    /// Creates a builder for a population, to set the number of each type of organism by name
+    ///
+    /// See `PopulationBuilder`.
+    pub fn builder() -> PopulationBuilder {
+        PopulationBuilder::default()
+    }
+
     /// Return the number of homozygous dominant organisms in the population
     pub fn count_homozygous_dominant(&self) -> u32 {
         self.0
@@ -249,6 +402,20 @@ impl Population {
     /// let parents = (Organism::heterozygous(), Organism::heterozygous());
     ///
     /// assert_eq!(pop.select_parents(parents.0, parents.1), 1f64 / 15f64);
+    ///
+    /// // The order of the allelles does not matter
+    /// let parent = Organism::new(Allelle::R, Allelle::D);
+    /// assert_eq!(pop.select_parents(parent, parent), 1f64 / 15f64);
+    ///
+    /// // Parents that are not in the population cannot be selected
+    /// let pop = Population::new(0, 2, 2);
+    /// let dominant = Organism::homozygous_dominant();
+    /// assert_eq!(pop.select_parents(dominant, Organism::heterozygous()), 0f64);
+    /// assert_eq!(pop.select_parents(Organism::heterozygous(), dominant), 0f64);
+    ///
+    /// // Selecting the only organism of a genotype leaves none for the second parent
+    /// let pop = Population::new(1, 2, 2);
+    /// assert_eq!(pop.select_parents(dominant, dominant), 0f64);
     /// ```
     pub fn select_parents(&self, p1: Organism, p2: Organism) -> f64 {
         let (prob_p1, new_pop) = self.select_organism(p1);
@@ -256,37 +423,184 @@ impl Population {
         prob_p1 * prob_p2
     }
 
+    // Return the probability of selecting an organism from the population, along with the
+    // population that remains after the selection. If the population has no organism of the given
+    // genotype, the probability is 0 and the population is unchanged
     fn select_organism(&self, org: Organism) -> (f64, Population) {
-        match &org.to_string()[..] {
-            "DD" => (
-                f64::from(self.count_homozygous_dominant()) / f64::from(self.size()),
-                Population(
-                    self.count_homozygous_dominant() - 1,
-                    self.count_heterozygous(),
-                    self.count_homozygous_recessive(),
-                ),
-            ),
-            "DR" => (
-                f64::from(self.count_heterozygous()) / f64::from(self.size()),
-                Population(
-                    self.count_homozygous_dominant(),
-                    self.count_heterozygous() - 1,
-                    self.count_homozygous_recessive(),
-                ),
-            ),
-            "RR" => (
-                f64::from(self.count_homozygous_recessive()) / f64::from(self.size()),
-                Population(
-                    self.count_homozygous_dominant(),
-                    self.count_heterozygous(),
-                    self.count_homozygous_recessive() - 1,
-                ),
-            ),
-            _ => (0f64, *self),
+        let Population(dd, dr, rr) = *self;
+
+        let (count, remaining) = match (org.0, org.1) {
+            (Allelle::D, Allelle::D) => (dd, dd.checked_sub(1).map(|dd| Population(dd, dr, rr))),
+            (Allelle::D, Allelle::R) | (Allelle::R, Allelle::D) => {
+                (dr, dr.checked_sub(1).map(|dr| Population(dd, dr, rr)))
+            }
+            (Allelle::R, Allelle::R) => (rr, rr.checked_sub(1).map(|rr| Population(dd, dr, rr))),
+        };
+
+        match remaining {
+            Some(remaining) => (f64::from(count) / f64::from(self.size()), remaining),
+            None => (0f64, *self),
         }
     }
 }
 
+impl PopulationBuilder {
+    /// Set the number of homozygous dominant organisms
+    pub fn homozygous_dominant(mut self, count: u32) -> PopulationBuilder {
+        self.dd = count;
+        self
+    }
+
+    /// Set the number of heterozygous organisms
+    pub fn heterozygous(mut self, count: u32) -> PopulationBuilder {
+        self.dr = count;
+        self
+    }
+
+    /// Set the number of homozygous recessive organisms
+    pub fn homozygous_recessive(mut self, count: u32) -> PopulationBuilder {
+        self.rr = count;
+        self
+    }
+
+    /// Create the population
+    pub fn build(self) -> Population {
+        Population(self.dd, self.dr, self.rr)
+    }
+}
+
+// ///////// //
+// Functions //
+// ///////// //
+
+/// Return the expected number of offspring displaying the dominant trait
+///
+/// Each entry of `couples` is the number of couples with the following parent genotypes, in order:
+///
+/// 1. homozygous dominant - homozygous dominant
+/// 2. homozygous dominant - heterozygous
+/// 3. homozygous dominant - homozygous recessive
+/// 4. heterozygous - heterozygous
+/// 5. heterozygous - homozygous recessive
+/// 6. homozygous recessive - homozygous recessive
+///
+/// Every couple is assumed to have `children_per_couple` children.
+///
+/// # Example
+/// ```rust
+/// use rosalind::gene::*;
+///
+/// assert_eq!(expected_dominant_offspring([1, 0, 0, 1, 0, 1], 2), 3.5);
+/// ```
+pub fn expected_dominant_offspring(couples: [u32; 6], children_per_couple: u32) -> f64 {
+    let pairings = [
+        (
+            Organism::homozygous_dominant(),
+            Organism::homozygous_dominant(),
+        ),
+        (Organism::homozygous_dominant(), Organism::heterozygous()),
+        (
+            Organism::homozygous_dominant(),
+            Organism::homozygous_recessive(),
+        ),
+        (Organism::heterozygous(), Organism::heterozygous()),
+        (Organism::heterozygous(), Organism::homozygous_recessive()),
+        (
+            Organism::homozygous_recessive(),
+            Organism::homozygous_recessive(),
+        ),
+    ];
+
+    couples
+        .iter()
+        .zip(pairings.iter())
+        .fold(0f64, |acc, (&count, &(p1, p2))| {
+            acc + f64::from(count) * f64::from(children_per_couple) * p1.has_dominant_child(p2)
+        })
+}
+
+/// Return the probability that at least `at_least` organisms of a generation are heterozygous
+///
+/// The family tree starts with a single organism, heterozygous for two independent factors
+/// (`Aa Bb`), in generation 0. Every organism in each generation mates with an `Aa Bb` organism
+/// and has two children, so generation `k` has `2^k` organisms. Each of them is `Aa Bb` with
+/// probability 1/4, independently of the others, so the number of `Aa Bb` organisms follows a
+/// binomial distribution.
+///
+/// Each binomial term is computed in log space, so the result stays accurate for large
+/// generations where the probability of any single outcome underflows. Only the tail of the
+/// distribution on the far side of the mean from `at_least` is summed, so small probabilities
+/// keep their relative precision.
+///
+/// The running time grows with the number of organisms, `2^generation`, so generations above
+/// about 30 are impractical.
+///
+/// # Panics
+///
+/// Panics if `generation` is 64 or more, since the generation would have more than `u64::MAX`
+/// organisms.
+///
+/// # Example
+/// ```rust
+/// use rosalind::gene::*;
+///
+/// assert!((at_least_n_heterozygous(2, 1) - 0.684).abs() < 1e-3);
+/// assert!((at_least_n_heterozygous(12, 1) - 1.0).abs() < 1e-9);
+/// assert!((at_least_n_heterozygous(12, 1024) - 0.506).abs() < 1e-3);
+/// assert!((at_least_n_heterozygous(13, 2048) - 0.504).abs() < 1e-3);
+/// assert!((at_least_n_heterozygous(12, 1500) / 4.0524e-61 - 1.0).abs() < 1e-3);
+/// assert_eq!(at_least_n_heterozygous(2, 5), 0.0);
+/// ```
+pub fn at_least_n_heterozygous(generation: u32, at_least: u32) -> f64 {
+    let organisms = 2u64
+        .checked_pow(generation)
+        .unwrap_or_else(|| panic!("Generation {} has too many organisms", generation));
+    let at_least = u64::from(at_least);
+
+    if at_least > organisms {
+        return 0f64;
+    }
+
+    // Split the distribution at its mean, `organisms / 4`
+    let total = if at_least <= organisms / 4 {
+        1f64 - binomial_lower_tail(organisms, at_least)
+    } else {
+        binomial_upper_tail(organisms, at_least)
+    };
+
+    total.clamp(0f64, 1f64)
+}
+
+// Return the probability that fewer than `to` of `n` organisms are `Aa Bb`, each with probability
+// 1/4. Terms are computed in log space, walking up from 0 organisms
+fn binomial_lower_tail(n: u64, to: u64) -> f64 {
+    let (ln_success, ln_failure) = (0.25f64.ln(), 0.75f64.ln());
+    let mut ln_choose = 0f64;
+    let mut total = 0f64;
+
+    for i in 0..to {
+        total += (ln_choose + i as f64 * ln_success + (n - i) as f64 * ln_failure).exp();
+        ln_choose += ((n - i) as f64).ln() - ((i + 1) as f64).ln();
+    }
+
+    total
+}
+
+// Return the probability that at least `from` of `n` organisms are `Aa Bb`, each with
+// probability 1/4. Terms are computed in log space, walking down from `n` organisms
+fn binomial_upper_tail(n: u64, from: u64) -> f64 {
+    let (ln_success, ln_failure) = (0.25f64.ln(), 0.75f64.ln());
+    let mut ln_choose = 0f64;
+    let mut total = 0f64;
+
+    for i in (from..=n).rev() {
+        total += (ln_choose + i as f64 * ln_success + (n - i) as f64 * ln_failure).exp();
+        ln_choose += (i as f64).ln() - ((n - i + 1) as f64).ln();
+    }
+
+    total
+}
+
 // /// Determine the percentage of the population with dominant genes
 // ///
 // /// # Example
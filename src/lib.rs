@@ -25,6 +25,7 @@
 //!     -V, --version    Prints version information
 //!
 //! SUBCOMMANDS:
+//!     cons    Consensus and Profile
 //!     dna     Counting DNA Nucleotides
 //!     fib     Rabbits and Recurrence Relations
 //!     fibd    Mortal Fibonacci Rabbits
@@ -32,6 +33,8 @@
 //!     hamm    Counting Point Mutations
 //!     help    Prints this message or the help of the given subcommand(s)
 //!     iprb    Introduction to Mendelian Inheritance
+//!     kmer    k-Mer Composition
+//!     lcsm    Finding a Shared Motif
 //!     mrna    Inferring mRNA from Protein
 //!     perm    Enumerating Gene Orders
 //!     prot    Translating RNA into Protein
@@ -82,6 +85,18 @@
 //! $ cargo build
 //! ```
 //!
+//! ### Optional features
+//!
+//! The library has the following optional cargo features, all disabled by default:
+//!
+//! - `bigint`: population and permutation counts that do not overflow, using `num-bigint`
+//! - `rand`: random genetic strings for tests and benchmarks, using `rand`
+//! - `serde`: serialization of the genetic string types, using `serde`
+//!
+//! ```bash
+//! $ cargo build --features bigint,rand,serde
+//! ```
+//!
 //! ## 3 Testing
 //!
 //! From within the `rosalind` repo directory use:
@@ -94,8 +109,12 @@
 pub mod fib;
 pub mod gen_str;
 pub mod gene;
+pub mod modulo_ops;
 pub mod perm;
 
+use crate::gen_str::GenError;
+use std::iter;
+
 // //////// //
 // Funtions //
 // //////// //
@@ -105,6 +124,10 @@ pub mod perm;
 /// The hamming distance represents the number of single-character changes required to convert one
 /// string to another.
 ///
+/// This function is lenient about the lengths of the strings: only the characters up to the length
+/// of the shorter string are compared, and any extra characters in the longer string are ignored.
+/// Use `checked_hamming_distance` to reject strings of different lengths.
+///
 /// # Example
 /// ```rust
 /// # use rosalind::hamming_distance;
@@ -119,6 +142,34 @@ pub fn hamming_distance(first: &str, other: &str) -> usize {
         .count()
 }
 
+/// Compute the Hamming distance between two strings of equal length
+///
+/// Unlike `hamming_distance`, this returns `GenError::MismatchedLength` if the strings contain a
+/// different number of characters, since the Hamming distance is only defined for strings of
+/// equal length.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::checked_hamming_distance;
+/// # use rosalind::gen_str::GenError;
+/// checked_hamming_distance("ACGTACGTAC", "AGGTACGTAA"); // Ok(2)
+/// # assert_eq!(checked_hamming_distance("ACGTACGTAC", "AGGTACGTAA"), Ok(2));
+/// checked_hamming_distance("ACGT", "ACG"); // Err(MismatchedLength { left: 4, right: 3 })
+/// # assert_eq!(
+/// #     checked_hamming_distance("ACGT", "ACG"),
+/// #     Err(GenError::MismatchedLength { left: 4, right: 3 })
+/// # );
+/// ```
+pub fn checked_hamming_distance(first: &str, other: &str) -> Result<usize, GenError> {
+    let (left, right) = (first.chars().count(), other.chars().count());
+
+    if left != right {
+        return Err(GenError::MismatchedLength { left, right });
+    }
+
+    Ok(hamming_distance(first, other))
+}
+
 /// Determine the positions of a substring in a given string
 ///
 /// Returns a list of indices representing the starting position of each occurence of the substring
@@ -132,31 +183,328 @@ pub fn hamming_distance(first: &str, other: &str) -> usize {
 /// # assert_eq!(substring_locations(source_string, substring), vec![1usize, 3, 9]);
 /// ```
 pub fn substring_locations(source_string: &str, substring: &str) -> Vec<usize> {
+    substring_iter(source_string, substring).collect::<Vec<_>>()
+}
+
+/// Iterate over the positions of a substring in a given string
+///
+/// This is a lazy version of `substring_locations`: each position is only searched for when it is
+/// requested, so the search can stop early. Overlapping matches are found.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::substring_iter;
+/// let locations = substring_iter("GATATATGCATATACTT", "ATAT");
+/// let first_two = locations.take(2).collect::<Vec<_>>();  // [1, 3]
+/// # assert_eq!(first_two, vec![1, 3]);
+/// ```
+pub fn substring_iter<'a>(
+    source_string: &'a str,
+    substring: &'a str,
+) -> impl Iterator<Item = usize> + 'a {
+    let source_string = source_string.trim();
+    let substring = substring.trim();
+    let mut offset = Some(0usize);
+
+    iter::from_fn(move || {
+        let start = offset?;
+        let location = source_string[start..].find(substring).map(|x| x + start);
+
+        // Advance by a single character after each match so that overlapping matches are found
+        offset = location
+            .map(|location| {
+                location
+                    + source_string[location..]
+                        .chars()
+                        .next()
+                        .map_or(1, |ch| ch.len_utf8())
+            })
+            .filter(|&offset| offset <= source_string.len());
+
+        location
+    })
+}
+
+/// Determine the positions of a substring in a given string, using the Knuth-Morris-Pratt algorithm
+///
+/// This returns the same positions as `substring_locations`, including overlapping matches, but
+/// takes time linear in the lengths of the two strings, rather than their product.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::kmp_search;
+/// kmp_search("GATATATGCATATACTT", "ATAT");  // [1, 3, 9]
+/// # assert_eq!(kmp_search("GATATATGCATATACTT", "ATAT"), vec![1usize, 3, 9]);
+/// ```
+pub fn kmp_search(source_string: &str, substring: &str) -> Vec<usize> {
+    let source_string = source_string.trim();
+    let substring = substring.trim();
+
+    // Every character boundary matches an empty substring
+    if substring.is_empty() {
+        return source_string
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain(iter::once(source_string.len()))
+            .collect::<Vec<_>>();
+    }
+
+    // Matching bytes finds the same positions as matching characters, since a valid UTF-8
+    // substring can only match at a character boundary
+    let pattern = substring.as_bytes();
+    let failure = failure_table(pattern);
     let mut locations: Vec<usize> = vec![];
-    get_substring_locations(
-        source_string.trim(),
-        substring.trim(),
-        &mut locations,
-        0usize,
-    )
+    let mut matched = 0usize;
+
+    for (index, byte) in source_string.bytes().enumerate() {
+        while matched > 0 && pattern[matched] != byte {
+            matched = failure[matched - 1];
+        }
+        if pattern[matched] == byte {
+            matched += 1;
+        }
+        if matched == pattern.len() {
+            locations.push(index + 1 - matched);
+            matched = failure[matched - 1];
+        }
+    }
+
+    locations
+}
+
+/// Compute the failure function of a string
+///
+/// This is the same table as `prefix_failure_array`.
+#[deprecated(note = "use prefix_failure_array")]
+pub fn failure_function(input: &str) -> Vec<usize> {
+    prefix_failure_array(input)
+}
+
+/// Compute the failure array of a string
+///
+/// The failure array has one entry per character of the string. The entry at position `k` is the
+/// length of the longest substring ending at `k` that is also a proper prefix of the string. This
+/// is the table used by the Knuth-Morris-Pratt algorithm to avoid re-examining characters.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::prefix_failure_array;
+/// prefix_failure_array("CAGCATGGTATCACAGCAGAG");
+/// // [0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 3, 4, 5, 3, 0, 0]
+/// # assert_eq!(
+/// #     prefix_failure_array("CAGCATGGTATCACAGCAGAG"),
+/// #     vec![0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 2, 1, 2, 3, 4, 5, 3, 0, 0]
+/// # );
+/// ```
+pub fn prefix_failure_array(input: &str) -> Vec<usize> {
+    failure_table(&input.chars().collect::<Vec<_>>())
 }
 
-fn get_substring_locations(
-    source_string: &str,
-    substring: &str,
-    locations: &mut Vec<usize>,
-    slice_offset: usize,
-) -> Vec<usize> {
-    match source_string.find(substring) {
-        Some(location) => {
-            locations.push(location + slice_offset);
-            get_substring_locations(
-                &source_string[(location + 1)..],
-                substring,
-                locations,
-                location + slice_offset + 1,
-            )
+/// Determine the positions of a subsequence in a given string
+///
+/// Unlike `substring_locations`, the symbols of the subsequence need not be contiguous in the
+/// source string, only in the same order. Returns the 1-based position of each symbol of the
+/// subsequence in its leftmost embedding, or `None` if the string does not contain the subsequence.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::subsequence_indices;
+/// subsequence_indices("ACGTACGTGACG", "GTA");  // Some([3, 4, 5])
+/// # assert_eq!(subsequence_indices("ACGTACGTGACG", "GTA"), Some(vec![3usize, 4, 5]));
+/// ```
+pub fn subsequence_indices(source_string: &str, subsequence: &str) -> Option<Vec<usize>> {
+    let mut positions = source_string.trim().chars().enumerate();
+
+    subsequence
+        .trim()
+        .chars()
+        .map(|symbol| {
+            positions
+                .find(|&(_, ch)| ch == symbol)
+                .map(|(index, _)| index + 1)
+        })
+        .collect::<Option<Vec<_>>>()
+}
+
+/// List every string of a given length over an ordered alphabet, in lexicographic order
+///
+/// The order of the strings follows the order of the symbols in `alphabet`, which need not be
+/// sorted. The result has `alphabet.len()^n` strings: a length of 0 produces a single empty
+/// string, and an empty alphabet produces no strings for any other length.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::lexicographic_kmers;
+/// lexicographic_kmers(&['T', 'A'], 2);  // ["TT", "TA", "AT", "AA"]
+/// # assert_eq!(lexicographic_kmers(&['T', 'A'], 2), vec!["TT", "TA", "AT", "AA"]);
+/// ```
+pub fn lexicographic_kmers(alphabet: &[char], n: usize) -> Vec<String> {
+    (0..n).fold(vec![String::new()], |kmers, _| {
+        kmers
+            .iter()
+            .flat_map(|kmer| {
+                alphabet
+                    .iter()
+                    .map(|symbol| format!("{}{}", kmer, symbol))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+/// List every string of length 1 up to `max_len` over an ordered alphabet, in lexicographic order
+///
+/// The order of the strings follows the order of the symbols in `alphabet`, which need not be
+/// sorted, and each string comes before its extensions. For example, with the alphabet `D, N, A`
+/// the list begins `D, DD, DDD, DDN, DDA, DN, ...`.
+///
+/// # Example
+/// ```rust
+/// # use rosalind::lexicographic_strings_upto;
+/// lexicographic_strings_upto(&['T', 'A'], 2);  // ["T", "TT", "TA", "A", "AT", "AA"]
+/// # assert_eq!(
+/// #     lexicographic_strings_upto(&['T', 'A'], 2),
+/// #     vec!["T", "TT", "TA", "A", "AT", "AA"]
+/// # );
+/// ```
+pub fn lexicographic_strings_upto(alphabet: &[char], max_len: usize) -> Vec<String> {
+    let mut strings: Vec<String> = vec![];
+    extend_strings(alphabet, max_len, &mut String::new(), &mut strings);
+    strings
+}
+
+// Append every extension of `prefix` up to `max_len` characters to `strings`, each one before its
+// own extensions
+fn extend_strings(
+    alphabet: &[char],
+    max_len: usize,
+    prefix: &mut String,
+    strings: &mut Vec<String>,
+) {
+    if prefix.chars().count() == max_len {
+        return;
+    }
+
+    for &symbol in alphabet {
+        prefix.push(symbol);
+        strings.push(prefix.clone());
+        extend_strings(alphabet, max_len, prefix, strings);
+        prefix.pop();
+    }
+}
+
+// Compute the Knuth-Morris-Pratt failure table of a sequence of symbols
+fn failure_table<T: PartialEq>(symbols: &[T]) -> Vec<usize> {
+    let mut failure = vec![0usize; symbols.len()];
+    let mut matched = 0usize;
+
+    for index in 1..symbols.len() {
+        while matched > 0 && symbols[index] != symbols[matched] {
+            matched = failure[matched - 1];
         }
-        None => locations.clone(),
+        if symbols[index] == symbols[matched] {
+            matched += 1;
+        }
+        failure[index] = matched;
+    }
+
+    failure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_compares_unequal_lengths_leniently_or_strictly() {
+        assert_eq!(hamming_distance("ACGTAA", "ACGT"), 0);
+        assert_eq!(
+            checked_hamming_distance("ACGTAA", "ACGT"),
+            Err(GenError::MismatchedLength { left: 6, right: 4 })
+        );
+    }
+
+    #[test]
+    fn it_lists_kmers_in_alphabet_order() {
+        let kmers = lexicographic_kmers(&['T', 'A', 'G', 'C'], 2);
+        assert_eq!(kmers.len(), 16);
+        assert_eq!(&kmers[..5], &["TT", "TA", "TG", "TC", "AT"]);
+        assert_eq!(kmers.last().unwrap(), "CC");
+
+        assert_eq!(lexicographic_kmers(&['A', 'C'], 0), vec![""]);
+        assert!(lexicographic_kmers(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn it_lists_strings_upto_a_length_in_alphabet_order() {
+        let strings = lexicographic_strings_upto(&['D', 'N', 'A'], 3);
+        assert_eq!(strings.len(), 3 + 9 + 27);
+        assert_eq!(
+            &strings[..8],
+            &["D", "DD", "DDD", "DDN", "DDA", "DN", "DND", "DNN"]
+        );
+        assert_eq!(&strings[36..], &["AAD", "AAN", "AAA"]);
+        assert!(lexicographic_strings_upto(&['D', 'N', 'A'], 0).is_empty());
+    }
+
+    #[test]
+    fn it_finds_subsequences() {
+        assert_eq!(
+            subsequence_indices("ACGTACGTGACG", "GTA"),
+            Some(vec![3, 4, 5])
+        );
+        assert_eq!(subsequence_indices("ACGT", "TA"), None);
+        assert_eq!(subsequence_indices("ACGT", ""), Some(vec![]));
+    }
+
+    #[test]
+    fn it_finds_substrings_lazily() {
+        let source_string = "A".repeat(1_000_000);
+        let mut locations = substring_iter(&source_string, "AA");
+        assert_eq!(locations.next(), Some(0));
+        assert_eq!(locations.next(), Some(1));
+        assert_eq!(substring_iter("ACGT", "T").last(), Some(3));
+        assert_eq!(substring_iter("ACGT", "TT").next(), None);
+    }
+
+    #[test]
+    fn it_finds_the_same_substrings_with_kmp() {
+        let cases = [
+            ("GATATATGCATATACTT", "ATAT"),
+            ("AAAAAA", "AA"),
+            ("ACGTACGT", "TT"),
+            ("ACGT", "ACGTA"),
+            ("ACGT", ""),
+            ("AABAACAABAA", "AABA"),
+        ];
+
+        for (source_string, substring) in cases.iter() {
+            assert_eq!(
+                kmp_search(source_string, substring),
+                substring_locations(source_string, substring)
+            );
+        }
+    }
+
+    #[test]
+    fn it_computes_failure_arrays() {
+        assert_eq!(prefix_failure_array(""), Vec::<usize>::new());
+        assert_eq!(prefix_failure_array("A"), vec![0]);
+        assert_eq!(prefix_failure_array("AAAA"), vec![0, 1, 2, 3]);
+        assert_eq!(prefix_failure_array("AABAAA"), vec![0, 1, 0, 1, 2, 2]);
+        assert_eq!(prefix_failure_array("ABABC"), vec![0, 0, 1, 2, 0]);
+    }
+
+    #[test]
+    fn it_finds_overlapping_substrings() {
+        assert_eq!(substring_locations("ATATAT", "ATAT"), vec![0usize, 2]);
+    }
+
+    #[test]
+    fn it_finds_many_substrings_without_recursing() {
+        let source_string = "A".repeat(1_000_000);
+        let locations = substring_locations(&source_string, "A");
+        assert_eq!(locations.len(), 1_000_000);
+        assert_eq!(locations.last(), Some(&999_999usize));
     }
 }
@@ -3,8 +3,16 @@ extern crate clap;
 extern crate rosalind;
 
 use clap::App;
+use std::process;
 
-fn main() -> Result<(), Box<std::error::Error>> {
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<std::error::Error>> {
     let yaml = load_yaml!("cli.yml");
     let matches = App::from_yaml(yaml).get_matches();
 
@@ -13,7 +21,7 @@ fn main() -> Result<(), Box<std::error::Error>> {
         ("rna", Some(rna_matches)) => runners::rna(rna_matches.value_of("dna_string").unwrap()),
         ("revc", Some(revc_matches)) => runners::revc(revc_matches.value_of("dna_string").unwrap()),
         ("prot", Some(prot_matches)) => runners::prot(prot_matches.value_of("rna_file").unwrap()),
-        ("gc", Some(gc_matches)) => runners::gc(gc_matches.value_of("dna_file").unwrap()),
+        ("gc", Some(gc_matches)) => runners::gc(gc_matches.value_of("dna_file").unwrap())?,
         ("fib", Some(fib_matches)) => runners::fib(
             fib_matches.value_of("months").unwrap().parse::<u8>()?,
             fib_matches.value_of("pairs").unwrap().parse::<u8>()?,
@@ -34,13 +42,13 @@ fn main() -> Result<(), Box<std::error::Error>> {
                 .value_of("permutation_length")
                 .unwrap()
                 .parse::<u8>()?,
-        ),
+        )?,
         ("sign", Some(sign_matches)) => runners::sign(
             sign_matches
                 .value_of("permutation_length")
                 .unwrap()
                 .parse::<u8>()?,
-        ),
+        )?,
         ("subs", Some(subs_matches)) => runners::subs(
             subs_matches.value_of("dna_string").unwrap(),
             subs_matches.value_of("substring").unwrap(),
@@ -62,6 +70,12 @@ fn main() -> Result<(), Box<std::error::Error>> {
                 .unwrap()
                 .parse::<u32>()?,
         ),
+        ("lcsm", Some(lcsm_matches)) => runners::lcsm(lcsm_matches.value_of("dna_file").unwrap())?,
+        ("cons", Some(cons_matches)) => runners::cons(cons_matches.value_of("dna_file").unwrap())?,
+        ("kmer", Some(kmer_matches)) => runners::kmer(
+            kmer_matches.value_of("dna_string").unwrap(),
+            kmer_matches.value_of("k").unwrap().parse::<usize>()?,
+        )?,
         ("", None) => println!("No subcommand was used"),
         _ => unreachable!(),
     }
@@ -77,14 +91,8 @@ mod runners {
     // use rosalind::*;
     use std::fs::File;
     use std::io::prelude::*;
-
-    fn fdna_from_string(fdna: &str) -> FASTA {
-        let mut lines = fdna.split_whitespace();
-        let label = lines.next().unwrap().to_string();
-        let dna_string = lines.collect::<Vec<&str>>().join("");
-
-        FASTA::new(DNA::new(&dna_string), &label)
-    }
+    use std::io::{self, BufReader, BufWriter};
+    use std::path::Path;
 
     pub fn dna(dna_string: &str) {
         let dna = DNA::new(dna_string);
@@ -115,23 +123,17 @@ mod runners {
         println!("{}", Protein::from(RNA::new(&rna_string)))
     }
 
-    pub fn gc(dna_file_name: &str) {
-        let mut f = File::open(dna_file_name).expect("file not found");
+    pub fn gc(dna_file_name: &str) -> Result<(), GenError> {
+        let f = File::open(dna_file_name)?;
 
-        let mut fasta_dna_strings = String::new();
-        f.read_to_string(&mut fasta_dna_strings)
-            .expect("something went wrong reading the file");
+        let records = FastaReader::new(BufReader::new(f)).collect::<Result<Vec<_>, _>>()?;
 
-        let fdna_array: Vec<FASTA> = fasta_dna_strings
-            .split('>')
-            .filter(|fdna| !fdna.is_empty())
-            .map(|fdna| fdna_from_string(fdna))
-            .collect();
-
-        fdna_array.iter().for_each(|fdna| {
+        if let Some((fdna, gc)) = max_gc_content(&records) {
             println!("{}", fdna.label());
-            println!("{}", fdna.gc_content())
-        });
+            println!("{}", gc)
+        }
+
+        Ok(())
     }
 
     pub fn fib(months: u8, pairs: u8) {
@@ -146,7 +148,7 @@ mod runners {
     pub fn fibd(months: u8, life_expectancy: u8) {
         println!(
             "{:?}\n",
-            population_with_moratilty(1, life_expectancy as usize)
+            population_with_mortality(1, life_expectancy as usize)
                 .nth((months - 1) as usize)
                 .unwrap()
         );
@@ -156,37 +158,47 @@ mod runners {
         println!("{}", rosalind::hamming_distance(string_1, string_2));
     }
 
-    pub fn perm(permutation_length: u8) {
-        // TODO: writeln! + stdout lock
-        println!("{}", factorial(u64::from(permutation_length)));
-        for code in permutations((1i64..=i64::from(permutation_length)).collect::<Vec<_>>()) {
-            println!("{}", code);
+    pub fn perm(permutation_length: u8) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut out = BufWriter::new(stdout.lock());
+        write_perm(&mut out, permutation_length)?;
+        out.flush()
+    }
+
+    pub fn sign(permutation_length: u8) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut out = BufWriter::new(stdout.lock());
+        write_sign(&mut out, permutation_length)?;
+        out.flush()
+    }
+
+    // Write the number of permutations of the given length, followed by each permutation
+    fn write_perm<W: Write>(out: &mut W, permutation_length: u8) -> io::Result<()> {
+        writeln!(out, "{}", factorial(u64::from(permutation_length)))?;
+        for code in permutations_i64((1i64..=i64::from(permutation_length)).collect::<Vec<_>>()) {
+            writeln!(out, "{}", code)?;
         }
+
+        Ok(())
     }
 
-    pub fn sign(permutation_length: u8) {
-        // TODO: writeln! + stdout lock
+    // Write the number of signed permutations of the given length, followed by each permutation
+    fn write_sign<W: Write>(out: &mut W, permutation_length: u8) -> io::Result<()> {
         let permutation_length_pow2 = 2u64.pow(u32::from(permutation_length));
 
         // Number of outputs
-        println!(
+        writeln!(
+            out,
             "{}",
             factorial(u64::from(permutation_length)) * permutation_length_pow2
-        );
+        )?;
 
         // Permutations
-        for code in permutations((1i64..=i64::from(permutation_length)).collect::<Vec<_>>()) {
-            let vec = &*code; // Deref from wrapper
-            for binary in 0..permutation_length_pow2 {
-                let binary = generate_binary(binary, permutation_length as usize);
-                let zipped = binary.iter().zip(vec.iter()).collect::<Vec<_>>();
-                let perm = zipped
-                    .into_iter()
-                    .map(|val| *val.0 * *val.1 as i64)
-                    .collect::<Vec<_>>();
-                println!("{}", VecWrapper::new(perm));
-            }
+        for perm in signed_permutations(i64::from(permutation_length)) {
+            writeln!(out, "{}", VecWrapper::new(perm))?;
         }
+
+        Ok(())
     }
 
     pub fn subs(dna_string: &str, substring: &str) {
@@ -235,4 +247,79 @@ mod runners {
 
         println!("{}", result);
     }
+
+    pub fn lcsm(dna_file_name: &str) -> Result<(), GenError> {
+        let f = File::open(dna_file_name)?;
+
+        let strands = FastaReader::new(BufReader::new(f))
+            .map(|fdna| fdna.map(|fdna| DNA::new(fdna.content())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match longest_common_substring(&strands) {
+            Some(substring) => println!("{}", substring),
+            None => println!("No common substring found"),
+        }
+
+        Ok(())
+    }
+
+    pub fn cons(dna_file_name: &str) -> Result<(), GenError> {
+        let f = File::open(dna_file_name)?;
+
+        let strands = FastaReader::new(BufReader::new(f))
+            .map(|fdna| fdna.map(|fdna| DNA::new(fdna.content())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // All strands must have the same length
+        let profile = profile_matrix(&strands)?;
+
+        println!("{}", consensus(&profile));
+        for (symbol, row) in DNA_SYMBOLS.iter().zip(profile.iter()) {
+            let counts = row.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            println!("{}: {}", symbol, counts.join(" "));
+        }
+
+        Ok(())
+    }
+
+    pub fn kmer(dna_string: &str, k: usize) -> Result<(), GenError> {
+        // The DNA string may be given directly, or as a FASTA file
+        let dna = if Path::new(dna_string).is_file() {
+            let f = File::open(dna_string)?;
+            DNA::new(read_single_fasta(BufReader::new(f))?.content())
+        } else {
+            DNA::new(dna_string)
+        };
+
+        let counts = dna
+            .kmer_composition(k)
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        println!("{}", counts.join(" "));
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn it_writes_permutations_one_per_line() {
+            let mut out = Vec::new();
+            write_perm(&mut out, 3).unwrap();
+            assert_eq!(
+                String::from_utf8(out).unwrap(),
+                "6\n1 2 3\n1 3 2\n2 1 3\n2 3 1\n3 1 2\n3 2 1\n"
+            );
+
+            let mut out = Vec::new();
+            write_sign(&mut out, 2).unwrap();
+            assert_eq!(
+                String::from_utf8(out).unwrap(),
+                "8\n-1 -2\n-1 2\n1 -2\n1 2\n-2 -1\n-2 1\n2 -1\n2 1\n"
+            );
+        }
+    }
 }
@@ -0,0 +1,161 @@
+//! Modular arithmetic
+//!
+//! The `Modulo` type used throughout this project comes from the `modular` crate, which provides
+//! the `Add`, `Sub` and `Mul` operators. Those operators panic when the moduli of their operands
+//! differ. This module extends `Modulo` with exponentiation and with checked operations that
+//! return a `GenError` instead. Since `Modulo` is defined in another crate, it is formatted for
+//! display through the `ModuloOps::display` wrapper.
+//!
+//! Products are computed in 64 bits before being reduced, so they never overflow. Note that
+//! `Modulo` stores its remainder as an `i32`, so moduli above `i32::MAX` cannot be represented.
+
+use crate::gen_str::GenError;
+use modular::{modulo, Modular, Modulo};
+use std::fmt;
+
+/// Additional operations on modular values
+pub trait ModuloOps {
+    /// Raise a modular value to the given power
+    ///
+    /// This uses exponentiation by squaring, reducing under the modulus after each step.
+    ///
+    /// # Example
+    /// ```rust
+    /// use modular::*;
+    /// use rosalind::modulo_ops::*;
+    ///
+    /// let base = modulo!(7, 5);
+    /// assert_eq!(base.pow(3).remainder(), 3);  // 343 mod 5
+    /// ```
+    fn pow(self, exp: u32) -> Modulo;
+
+    /// Add two modular values, returning an error if their moduli differ
+    ///
+    /// # Example
+    /// ```rust
+    /// use modular::*;
+    /// use rosalind::gen_str::GenError;
+    /// use rosalind::modulo_ops::*;
+    ///
+    /// assert_eq!(modulo!(3, 5).checked_add(modulo!(4, 5)).unwrap().remainder(), 2);
+    /// assert_eq!(
+    ///     modulo!(3, 5).checked_add(modulo!(4, 7)).err(),
+    ///     Some(GenError::MismatchedModulus { left: 5, right: 7 })
+    /// );
+    /// ```
+    fn checked_add(self, rhs: Modulo) -> Result<Modulo, GenError>;
+
+    /// Multiply two modular values, returning an error if their moduli differ
+    ///
+    /// Unlike the `Mul` operator, the intermediate product cannot overflow.
+    ///
+    /// # Example
+    /// ```rust
+    /// use modular::*;
+    /// use rosalind::gen_str::GenError;
+    /// use rosalind::modulo_ops::*;
+    ///
+    /// assert_eq!(modulo!(3, 5).checked_mul(modulo!(4, 5)).unwrap().remainder(), 2);
+    /// assert_eq!(
+    ///     modulo!(3, 5).checked_mul(modulo!(4, 7)).err(),
+    ///     Some(GenError::MismatchedModulus { left: 5, right: 7 })
+    /// );
+    /// ```
+    fn checked_mul(self, rhs: Modulo) -> Result<Modulo, GenError>;
+
+    /// Wrap a modular value for display, as its remainder followed by its modulus
+    ///
+    /// # Example
+    /// ```rust
+    /// use modular::*;
+    /// use rosalind::modulo_ops::*;
+    ///
+    /// assert_eq!(modulo!(2, 5).display().to_string(), "2 (mod 5)");
+    /// ```
+    fn display(self) -> ModuloDisplay;
+}
+
+/// Formats a modular value for display
+///
+/// This is created by `ModuloOps::display`.
+#[derive(Clone, Copy, Debug)]
+pub struct ModuloDisplay(Modulo);
+
+impl fmt::Display for ModuloDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ModuloDisplay(ref value) = *self;
+        write!(f, "{} (mod {})", value.remainder(), value.modulus())
+    }
+}
+
+impl ModuloOps for Modulo {
+    fn pow(self, exp: u32) -> Modulo {
+        let modulus = i64::from(self.modulus());
+        let mut base = i64::from(self.remainder()).rem_euclid(modulus);
+        let mut exp = exp;
+        let mut result = 1i64 % modulus;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base % modulus;
+            }
+            base = base * base % modulus;
+            exp >>= 1;
+        }
+
+        reduce(result, self.modulus())
+    }
+
+    fn checked_add(self, rhs: Modulo) -> Result<Modulo, GenError> {
+        check_moduli(self, rhs)?;
+        Ok(self + rhs)
+    }
+
+    fn checked_mul(self, rhs: Modulo) -> Result<Modulo, GenError> {
+        check_moduli(self, rhs)?;
+        let product = i64::from(self.remainder()) * i64::from(rhs.remainder());
+        Ok(reduce(product, self.modulus()))
+    }
+
+    fn display(self) -> ModuloDisplay {
+        ModuloDisplay(self)
+    }
+}
+
+// Reduce a value under a modulus
+fn reduce(value: i64, modulus: u32) -> Modulo {
+    modulo!(value.rem_euclid(i64::from(modulus)) as i32, modulus)
+}
+
+// Return an error if two modular values have different moduli
+fn check_moduli(left: Modulo, right: Modulo) -> Result<(), GenError> {
+    if left.modulus() == right.modulus() {
+        Ok(())
+    } else {
+        Err(GenError::MismatchedModulus {
+            left: left.modulus(),
+            right: right.modulus(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_multiplies_without_overflow() {
+        // The product of the remainders overflows an `i32` before it is reduced
+        let modulus = i32::MAX as u32;
+        let value = modulo!(i32::MAX - 1, modulus);
+        assert_eq!(value.checked_mul(value).unwrap().remainder(), 1);
+        assert_eq!(value.pow(2).remainder(), 1);
+        assert_eq!(value.pow(3).remainder(), i32::MAX - 1);
+    }
+
+    #[test]
+    fn it_displays_the_remainder_and_modulus() {
+        assert_eq!(modulo!(2, 5).display().to_string(), "2 (mod 5)");
+        assert_eq!(format!("{}", modulo!(7, 5).pow(3).display()), "3 (mod 5)");
+    }
+}
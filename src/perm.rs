@@ -1,5 +1,9 @@
 //! Permutations of vectors
 
+use crate::modulo_ops::ModuloOps;
+use modular::{modulo, Modular, Modulo};
+#[cfg(feature = "bigint")]
+use num_bigint::BigUint;
 use std::fmt;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -44,6 +48,11 @@ pub fn generate_binary(from: u64, pad_to: usize) -> Vec<i64> {
 
 /// Generate the factorial of a given, positive number
 ///
+/// # Panics
+///
+/// Panics if the factorial does not fit in a `u64` (for numbers greater than 20). Use
+/// `checked_factorial` or `factorial_u128` for larger numbers.
+///
 /// # Example
 ///
 /// ```
@@ -52,18 +61,145 @@ pub fn generate_binary(from: u64, pad_to: usize) -> Vec<i64> {
 /// assert_eq!(factorial(5u64), 120u64);
 /// ```
 pub fn factorial(num: u64) -> u64 {
-    if num == 0 || num == 1 {
-        1u64
-    } else {
-        num * factorial(num - 1)
+    checked_factorial(num).expect("Factorial overflow")
+}
+
+/// Generate the factorial of a given, positive number, returning `None` on overflow
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert_eq!(checked_factorial(20u64), Some(2_432_902_008_176_640_000u64));
+/// assert_eq!(checked_factorial(21u64), None);
+/// ```
+pub fn checked_factorial(num: u64) -> Option<u64> {
+    (2..=num).try_fold(1u64, |acc, x| acc.checked_mul(x))
+}
+
+/// Generate the factorial of a given, positive number as a `u128`
+///
+/// # Panics
+///
+/// Panics if the factorial does not fit in a `u128` (for numbers greater than 34). Use
+/// `checked_factorial_u128` to handle this case.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert_eq!(factorial_u128(21u64), 51_090_942_171_709_440_000u128);
+/// ```
+pub fn factorial_u128(num: u64) -> u128 {
+    checked_factorial_u128(num).expect("Factorial overflow")
+}
+
+/// Generate the factorial of a given, positive number as a `u128`, returning `None` on overflow
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert!(checked_factorial_u128(34u64).is_some());
+/// assert_eq!(checked_factorial_u128(35u64), None);
+/// ```
+pub fn checked_factorial_u128(num: u64) -> Option<u128> {
+    (2..=num).try_fold(1u128, |acc, x| acc.checked_mul(u128::from(x)))
+}
+
+/// Generate the factorial of a given, positive number, without overflowing
+///
+/// Requires the `bigint` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "bigint")]
+/// # {
+/// use rosalind::perm::*;
+///
+/// assert_eq!(factorial_big(25u64).to_string(), "15511210043330985984000000");
+/// # }
+/// ```
+#[cfg(feature = "bigint")]
+pub fn factorial_big(num: u64) -> BigUint {
+    partial_permutations_big(num, num)
+}
+
+/// Count the partial permutations of `k` elements out of `n`, without overflowing
+///
+/// This is the falling factorial `n * (n - 1) * ... * (n - k + 1)`. There are no partial
+/// permutations if `k` is greater than `n`. Requires the `bigint` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "bigint")]
+/// # {
+/// use rosalind::perm::*;
+///
+/// assert_eq!(partial_permutations_big(21, 7).to_string(), "586051200");
+/// assert_eq!(partial_permutations_big(3, 4).to_string(), "0");
+/// # }
+/// ```
+#[cfg(feature = "bigint")]
+pub fn partial_permutations_big(n: u64, k: u64) -> BigUint {
+    if k > n {
+        return BigUint::from(0u32);
+    }
+
+    (n - k + 1..=n).fold(BigUint::from(1u32), |acc, x| acc * x)
+}
+
+/// Count the subsets of a set with `n` elements, under the given modulus
+///
+/// A set with `n` elements has `2^n` subsets (including the empty set and the set itself). The
+/// count is computed with modular exponentiation, so it does not overflow for large `n`.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert_eq!(subset_count(3, 1000).remainder(), 8);
+/// assert_eq!(subset_count(1000, 1_000_000).remainder(), 69376);
+/// ```
+pub fn subset_count(n: u32, modulus: u32) -> Modulo {
+    modulo!(2, modulus).pow(n)
+}
+
+/// Count the partial permutations of `k` elements out of `n`, under the given modulus
+///
+/// This is the falling factorial `n * (n - 1) * ... * (n - k + 1)`, computed under the modulus so
+/// that it does not overflow. There are no partial permutations if `k` is greater than `n`.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert_eq!(partial_permutations(21, 7, 1_000_000).remainder(), 51200);
+/// assert_eq!(partial_permutations(3, 4, 1_000_000).remainder(), 0);
+/// ```
+pub fn partial_permutations(n: u32, k: u32, modulus: u32) -> Modulo {
+    if k > n {
+        return modulo!(0, modulus);
     }
+
+    (n - k + 1..=n).fold(modulo!(1, modulus), |acc, x| {
+        acc.checked_mul(modulo!((x % modulus) as i32, modulus))
+            .expect("Moduli are equal")
+    })
 }
 
 /// Represents a step in an iteration of permutations of a given vector
 #[derive(Debug)]
-pub struct Permutation {
+pub struct Permutation<T: Clone> {
     curr: usize,
-    base_vector: Vec<i64>,
+    base_vector: Vec<T>,
 }
 
 /// Wraps a vector to allow for pretty-printing it
@@ -92,32 +228,193 @@ impl VecWrapper {
     }
 }
 
-impl Iterator for Permutation {
-    type Item = VecWrapper;
+impl<T: Clone> Iterator for Permutation<T> {
+    type Item = Vec<T>;
 
-    fn next(&mut self) -> Option<VecWrapper> {
-        let vector_length = self.base_vector.len();
-
-        if self.curr < factorial(vector_length as u64) as usize {
-            let lehmer_code = generate_lehmer_code(self.curr as i64, vector_length);
-            let mut _base_vector = self.base_vector.to_vec();
-            let perm = lehmer_code
-                .iter()
-                .map(|i| _base_vector.remove((*i) as usize))
-                .collect::<Vec<_>>();
+    fn next(&mut self) -> Option<Vec<T>> {
+        let perm = permutation_at(&self.base_vector, self.curr);
 
+        if perm.is_some() {
             self.curr += 1;
-            Some(VecWrapper::new(perm))
-        } else {
-            None
         }
+        perm
     }
 }
 
 /// Iterate through the permutations of a given vector
-pub fn permutations(vector: Vec<i64>) -> Permutation {
+///
+/// The permutations are generated in lexicographic order of the positions of the elements in the
+/// given vector.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// let perms = permutations(vec!['a', 'b', 'c']).collect::<Vec<_>>();
+/// assert_eq!(perms.len(), 6);
+/// assert_eq!(perms[1], vec!['a', 'c', 'b']);
+/// ```
+pub fn permutations<T: Clone>(vector: Vec<T>) -> Permutation<T> {
     Permutation {
         curr: 0usize,
         base_vector: vector,
     }
 }
+
+/// Return the permutation at the given (0-based) position in the iteration of permutations of a
+/// given vector
+///
+/// The permutation is decoded directly from the Lehmer code of its position, in `O(n^2)` time,
+/// without generating the permutations before it. Returns `None` if the position is not less than
+/// the number of permutations.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// let base = vec![1, 2, 3, 4];
+/// assert_eq!(permutation_at(&base, 7), Some(vec![2, 1, 4, 3]));
+/// assert_eq!(permutation_at(&base, 24), None);
+///
+/// for k in &[0, 1, 5, 13, 23] {
+///     assert_eq!(permutation_at(&base, *k), permutations(base.clone()).nth(*k));
+/// }
+/// ```
+pub fn permutation_at<T: Clone>(base: &[T], k: usize) -> Option<Vec<T>> {
+    // A count that overflows a `u64` can never be reached, so every position is valid
+    if let Some(count) = checked_factorial(base.len() as u64) {
+        if k as u64 >= count {
+            return None;
+        }
+    }
+
+    let mut remaining = base.to_vec();
+    Some(
+        generate_lehmer_code(k as i64, base.len())
+            .iter()
+            .map(|i| remaining.remove(*i as usize))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Iterate through the permutations of a given vector of integers, wrapped for pretty-printing
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// let perm = permutations_i64(vec![1, 2, 3]).nth(1).unwrap();
+/// assert_eq!(perm.to_string(), "1 3 2");
+/// ```
+pub fn permutations_i64(vector: Vec<i64>) -> impl Iterator<Item = VecWrapper> {
+    permutations(vector).map(VecWrapper::new)
+}
+
+/// Iterate through the signed permutations of the integers from 1 to `n`
+///
+/// Each permutation is repeated with every combination of signs of its elements, so there are
+/// `factorial(n) * 2^n` signed permutations in total.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// let perms = signed_permutations(2).collect::<Vec<_>>();
+/// assert_eq!(
+///     perms,
+///     vec![
+///         vec![-1, -2],
+///         vec![-1, 2],
+///         vec![1, -2],
+///         vec![1, 2],
+///         vec![-2, -1],
+///         vec![-2, 1],
+///         vec![2, -1],
+///         vec![2, 1],
+///     ]
+/// );
+/// assert_eq!(signed_permutations(4).count() as u64, factorial(4) * 2u64.pow(4));
+/// ```
+pub fn signed_permutations(n: i64) -> impl Iterator<Item = Vec<i64>> {
+    let length = n.max(0) as usize;
+
+    permutations((1..=n).collect::<Vec<_>>()).flat_map(move |perm| {
+        (0..2u64.pow(length as u32)).map(move |signs| {
+            generate_binary(signs, length)
+                .iter()
+                .zip(perm.iter())
+                .map(|(sign, value)| sign * value)
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+/// Find a longest increasing subsequence of a sequence
+///
+/// The subsequence is strictly increasing and is found with the patience sorting algorithm, in
+/// `O(n log n)` time. If there are several longest increasing subsequences, one of them is
+/// returned.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// assert_eq!(longest_increasing_subsequence(&[5, 1, 4, 2, 3]), vec![1, 2, 3]);
+/// ```
+pub fn longest_increasing_subsequence(seq: &[i64]) -> Vec<i64> {
+    longest_subsequence(seq, |a, b| a < b)
+}
+
+/// Find a longest decreasing subsequence of a sequence
+///
+/// The subsequence is strictly decreasing. See `longest_increasing_subsequence`.
+///
+/// # Example
+///
+/// ```
+/// use rosalind::perm::*;
+///
+/// let lds = longest_decreasing_subsequence(&[5, 1, 4, 2, 3]);
+/// assert_eq!(lds.len(), 3);
+/// assert!(lds == vec![5, 4, 2] || lds == vec![5, 4, 3]);
+/// ```
+pub fn longest_decreasing_subsequence(seq: &[i64]) -> Vec<i64> {
+    longest_subsequence(seq, |a, b| a > b)
+}
+
+// Find a longest subsequence in which each element precedes the next, using patience sorting.
+// `tails[k]` holds the index of the smallest possible last element of a subsequence of length
+// `k + 1`, and `previous` links each element to its predecessor in the subsequence ending there.
+fn longest_subsequence(seq: &[i64], precedes: fn(i64, i64) -> bool) -> Vec<i64> {
+    let mut tails: Vec<usize> = vec![];
+    let mut previous: Vec<Option<usize>> = vec![None; seq.len()];
+
+    for (i, &x) in seq.iter().enumerate() {
+        let pile = tails.partition_point(|&tail| precedes(seq[tail], x));
+
+        if pile > 0 {
+            previous[i] = Some(tails[pile - 1]);
+        }
+
+        if pile == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pile] = i;
+        }
+    }
+
+    let mut subsequence: Vec<i64> = vec![];
+    let mut current = tails.last().cloned();
+
+    while let Some(i) = current {
+        subsequence.push(seq[i]);
+        current = previous[i];
+    }
+
+    subsequence.into_iter().rev().collect::<Vec<_>>()
+}